@@ -0,0 +1,91 @@
+// Known answers for an input, stored next to it as data/dayNN/answers.txt
+// A. Drew
+//
+// The file holds one `partN = answer` line per known part, for example
+//
+//   part1 = 1034
+//   part2 = 6166
+//
+// Blank lines and lines starting with # are ignored.
+
+use crate::runner::Part;
+use crate::ParseError;
+use std::path::Path;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, ParseError> {
+        text.lines()
+            .enumerate()
+            .try_fold(Answers::default(), |mut answers, (i, line)| {
+                let entry = line.trim();
+                if entry.is_empty() || entry.starts_with('#') {
+                    return Ok(answers);
+                }
+                let (key, value) = entry
+                    .split_once('=')
+                    .ok_or_else(|| ParseError::at_line(i + 1, line, line.len(), "'='"))?;
+                let slot = match key.trim() {
+                    "part1" => &mut answers.part1,
+                    "part2" => &mut answers.part2,
+                    _ => return Err(ParseError::at(line, key, "part1 or part2").on_line(i + 1)),
+                };
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|v| v.strip_suffix('"'))
+                    .unwrap_or(value);
+                *slot = Some(value.to_owned());
+                Ok(answers)
+            })
+    }
+
+    // Load the answers at path. A missing file is an error rather than nothing known,
+    // so that checking against it cannot pass without comparing anything.
+    pub fn load(path: &Path) -> Result<Answers, crate::Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ok(Answers::parse(&text)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let e = "there is no answers file to check against";
+                Err(std::io::Error::new(std::io::ErrorKind::NotFound, e).into())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day 1\npart1 = 3\n\npart2 = \"6\"\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some("3"));
+        assert_eq!(answers.get(Part::Two), Some("6"));
+        let answers = Answers::parse("part2=6").unwrap();
+        assert_eq!(answers.get(Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Answers::parse("part1 = 3\npart3 = 4\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = Answers::parse("part1 3\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 8));
+        let e = Answers::load(Path::new("no/such/answers.txt")).unwrap_err();
+        assert_eq!(e.to_string(), "there is no answers file to check against");
+    }
+}
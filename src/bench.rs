@@ -0,0 +1,131 @@
+// Time each stage of a day's solution over repeated runs
+// A. Drew
+
+use crate::runner::{Day, Part, Solved};
+use crate::{Error, Settings};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl std::fmt::Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        samples.sort();
+        let n = samples.len();
+        let median = match n {
+            0 => return None,
+            n if n % 2 == 0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            n => samples[n / 2],
+        };
+        Some(Stats {
+            iterations: n,
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        })
+    }
+}
+
+// Solve the text for the settings the given number of times and summarise how
+// long each stage took. Parts without an answer are left out.
+pub fn bench(
+    day: &Day,
+    text: &str,
+    parts: &[Part],
+    settings: &Settings,
+    iterations: usize,
+) -> Result<Vec<(Stage, Stats)>, Error> {
+    let mut samples: Vec<(Stage, Vec<Duration>)> = std::iter::once(Stage::Parse)
+        .chain(parts.iter().map(|&part| Stage::Part(part)))
+        .map(|stage| (stage, Vec::with_capacity(iterations)))
+        .collect();
+    for _ in 0..iterations {
+        let Solved {
+            parse_elapsed,
+            answers,
+            ..
+        } = day.solve(text, parts, settings)?;
+        samples[0].1.push(parse_elapsed);
+        for (answer, (_, times)) in answers.iter().zip(&mut samples[1..]) {
+            if answer.value.is_ok() {
+                times.push(answer.elapsed);
+            }
+        }
+    }
+    Ok(samples
+        .into_iter()
+        .filter_map(|(stage, times)| Stats::from_samples(times).map(|stats| (stage, stats)))
+        .collect())
+}
+
+// One comma separated line per stage, matching CSV_HEADER
+pub const CSV_HEADER: &str = "day,stage,iterations,min_ns,median_ns,max_ns";
+
+pub fn csv_row(day: u32, stage: Stage, stats: &Stats) -> String {
+    format!(
+        "{},{},{},{},{},{}",
+        day,
+        stage,
+        stats.iterations,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.max.as_nanos()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(5)));
+        let stats = Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(Stats::from_samples(vec![]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let day = Day::find(9).unwrap();
+        let stats = bench(
+            day,
+            "7,1\n11,1\n11,7\n",
+            &Part::ALL,
+            &Settings::default(),
+            3,
+        )
+        .unwrap();
+        let stages: Vec<_> = stats.iter().map(|(stage, _)| *stage).collect();
+        assert_eq!(stages, [Stage::Parse, Stage::Part(Part::One)]);
+        assert!(stats.iter().all(|(_, stats)| stats.iterations == 3));
+        assert_eq!(
+            csv_row(9, Stage::Part(Part::One), &stats[1].1)
+                .split(',')
+                .count(),
+            CSV_HEADER.split(',').count()
+        );
+    }
+}
@@ -0,0 +1,6 @@
+// Advent of Code 2025
+// A. Drew
+
+fn main() -> std::process::ExitCode {
+    aoc2025::cli::runner_main()
+}
@@ -1,125 +1,110 @@
 // Advent of Code 2025 Day 1
 // A. Drew
 
-fn main() {
-    use std::fs::File;
-    use std::io::{BufRead, BufReader};
-
-    let rotations = BufReader::new(File::open("data/day01/input.txt").unwrap())
-        .lines()
-        .map(|line| parse_rotation(&line.unwrap()).unwrap());
-
-    let passwords = process(rotations).fold([0, 0], |passwords, (position, clicks)| {
-        [
-            passwords[0] + if position == 0 { 1 } else { 0 },
-            passwords[1] + clicks,
-        ]
-    });
-    println!("passwords: {:?}", passwords);
-}
-
-fn parse_rotation(line: &str) -> Result<isize, nom::Err<nom::error::Error<&str>>> {
-    use nom::{
-        character::complete::{char, digit1},
-        combinator::all_consuming,
-        Parser,
+use aoc2025::cli::{self, DialOptions, Format, Options};
+use aoc2025::day01::{self, Checkpoint, Dial};
+use aoc2025::input::Source;
+use aoc2025::runner::{Day, Part};
+use aoc2025::{Error, Settings};
+use std::path::Path;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let (options, args) = match cli::split_dial_args(std::env::args().skip(1).collect()) {
+        Ok(split) => split,
+        Err(e) => return cli::usage(e),
+    };
+    let result = match (&options.checkpoint, options.trace) {
+        (Some(path), _) => stream(&options, path, args),
+        (None, true) => print_trace(&options, args).map(|()| true),
+        (None, false) => {
+            let settings = Settings {
+                dial: options.dial,
+                ..Settings::default()
+            };
+            return cli::day_main_with(1, args, settings);
+        }
     };
-    let direction = char('L').map(|_| -1).or(char('R').map(|_| 1));
-    let magnitude = digit1.map_res(str::parse::<isize>);
-    let rotation = direction.and(magnitude).map(|(d, m)| d * m);
-    all_consuming(rotation).parse(line).map(|(_, x)| x)
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(Error::Usage(e)) => cli::usage(Error::Usage(e)),
+        Err(e) => {
+            eprintln!("day 01: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
 
-pub struct Dial {
-    position: isize,
+// The input named by the usual options
+fn source(args: Vec<String>) -> Result<Source, Error> {
+    Ok(Options::parse_day(1, args)?
+        .input
+        .unwrap_or_else(|| Day::find(1).expect("day 1 exists").input()))
 }
 
-impl Dial {
-    const SIZE: isize = 100;
-
-    pub fn new(position: isize) -> Self {
-        Dial {
-            position: position.rem_euclid(Self::SIZE),
-        }
-    }
-
-    pub fn read(&self) -> isize {
-        self.position
+// Print every step of the input as text instead of the answers
+fn print_trace(options: &DialOptions, args: Vec<String>) -> Result<(), Error> {
+    let day_options = Options::parse_day(1, args.clone())?;
+    let answers = day_options.check || day_options.bench.is_some();
+    if answers || day_options.format != Format::Text || day_options.parts != Part::ALL {
+        let e = "--trace and --render print every step as text, not the answers";
+        return Err(Error::Usage(e.to_owned()));
     }
-
-    pub fn spin(&mut self, rotation: isize) -> isize {
-        let clicks = Self::count_zero_clicks(self.position, rotation);
-        self.position = (self.position + rotation).rem_euclid(Self::SIZE);
-        clicks
+    let runs = day01::parse_log(&source(args)?.read_to_string()?)?;
+    if options.render {
+        println!("{}", day01::render(&options.dial));
     }
-
-    fn count_zero_clicks(position: isize, rotation: isize) -> isize {
-        if rotation.is_negative() {
-            // translate to an equivalent positive rotation
-            ((-position).rem_euclid(Self::SIZE) - rotation) / Self::SIZE
-        } else {
-            (position + rotation) / Self::SIZE
+    for step in day01::trace(options.dial, day01::expand(&runs)) {
+        println!("{}", step);
+        if options.render {
+            let dial = Dial::with_size(options.dial.size(), step.end);
+            println!("{}", day01::render(&dial));
         }
     }
+    Ok(())
 }
 
-// Used to iterate over the positions and clicks from initial position 50 using the input rotations
-fn process(rotations: impl IntoIterator<Item = isize>) -> impl Iterator<Item = (isize, isize)> {
-    rotations.into_iter().scan(Dial::new(50), |dial, rotation| {
-        let clicks = dial.spin(rotation);
-        Some((dial.read(), clicks))
-    })
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const CONTENT: &str = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
-
-    const ROTATIONS: [isize; 10] = [-68, -30, 48, -5, 60, -55, -1, -99, 14, -82];
-
-    #[test]
-    fn test_parse_sample() {
-        let rotations: Vec<_> = CONTENT
-            .lines()
-            .map(|line| parse_rotation(line).unwrap())
-            .collect();
-        assert_eq!(rotations, ROTATIONS);
+// Carry on from the checkpoint at path, if there is one, through the input and save
+// where it got to. Returns false if any lines were skipped.
+fn stream(options: &DialOptions, path: &Path, args: Vec<String>) -> Result<bool, Error> {
+    let day_options = Options::parse_day(1, args.clone())?;
+    let answers = day_options.check || day_options.bench.is_some();
+    if answers || day_options.format != Format::Text || day_options.parts != Part::ALL {
+        let e = "--checkpoint prints both parts as text, not checked or timed";
+        return Err(Error::Usage(e.to_owned()));
     }
-
-    #[test]
-    fn test_process_sample() {
-        let xs: Vec<_> = process(ROTATIONS).collect();
-        assert_eq!(
-            xs,
-            [
-                (82, 1),
-                (52, 0),
-                (0, 1),
-                (95, 0),
-                (55, 1),
-                (0, 1),
-                (99, 0),
-                (0, 1),
-                (14, 0),
-                (32, 1)
-            ]
-        );
+    let source = source(args)?;
+    let mut checkpoint = match std::fs::read_to_string(path) {
+        Ok(text) => Checkpoint::parse(&text)?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Checkpoint::new(options.dial),
+        Err(e) => return Err(e.into()),
+    };
+    // a saved dial carries on as it was, so the options may only agree with it
+    let saved = checkpoint.dial;
+    if options.size_given && saved.size() != options.dial.size() {
+        return Err(Error::Usage(format!(
+            "--dial-size {} does not match the {} positions saved in {}",
+            options.dial.size(),
+            saved.size(),
+            path.display()
+        )));
     }
-
-    #[test]
-    fn test_clicks_with_cycles() {
-        assert_eq!(Dial::new(0).spin(300), 3);
-        assert_eq!(Dial::new(10).spin(-110), 2);
+    if options.start_given && saved.read() != options.dial.read() {
+        return Err(Error::Usage(format!(
+            "--dial-start {} does not match position {} saved in {}",
+            options.dial.read(),
+            saved.read(),
+            path.display()
+        )));
     }
+    let mut skipped = 0;
+    checkpoint.feed(source.open()?, |e| {
+        eprintln!("day 01: {}: skipped {}", source, e);
+        skipped += 1;
+    })?;
+    std::fs::write(path, format!("{}\n", checkpoint))?;
+    println!("day 01 part 1: {}", checkpoint.landings);
+    println!("day 01 part 2: {}", checkpoint.clicks);
+    Ok(skipped == 0)
 }
@@ -0,0 +1,715 @@
+// Command line shared by the aoc runner and the per-day binaries
+// A. Drew
+
+use crate::answers::Answers;
+use crate::bench::{bench, csv_row, Stage, Stats, CSV_HEADER};
+use crate::day01::Dial;
+use crate::day02::Reversed;
+use crate::input::{Source, DATA_DIR_VAR};
+use crate::json::{self, Value};
+use crate::rng::Rng;
+use crate::runner::{Answer, Day, Part, Solved, DAYS};
+use crate::{Error, Settings};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+usage: aoc run <day|all> [options]
+       aoc generate <day> [--size <n>] [--seed <n>]
+       dayNN [options]
+       day01 [--dial-size <n>] [--dial-start <n>] [--trace] [--render]
+             [--checkpoint <path>] [options]
+       day02 [--radix <n>] [--rule <rule>] [--reversed <swap|skip>] [options]
+options:
+  --part <1|2>     solve only one part
+  --input <path>   read the puzzle input from path, or stdin if path is -
+  --check          compare the answers with data/dayNN/answers.txt
+  --answers <path> read the known answers from path instead
+  --bench <n>      time parsing and each part over n runs
+  --bench-csv <path>
+                   write the timings to path, bench.csv by default
+  --format <text|json>
+                   print answers as text, or as one JSON record per line
+  --size <n>       how much input to generate, 100 by default
+  --seed <n>       seed for the generated input, random by default
+  --dial-size <n>  positions on the day 1 dial, 100 by default
+  --dial-start <n> where the day 1 dial starts, 50 by default
+  --trace          print every day 1 rotation instead of the answers
+  --render         trace, drawing the day 1 dial after every rotation
+  --checkpoint <path>
+                   stream the day 1 input, carrying on from the checkpoint
+                   at path and saving it there afterwards, printing both
+                   parts as text; the dial options only start a new one
+  --radix <n>      read and sum day 2 ids written in base n, from 2 to 36,
+                   giving the sums in base n as well
+  --rule <rule>    sum the day 2 ids invalid by the rule instead of the parts,
+                   as text only: twice, any, times(k), palindrome or
+                   periodic(n), combined with !, &, | and brackets. twice,
+                   any and times(k) are summed directly, but the others test
+                   every id in each range, which is slow for ranges of many
+                   ids
+  --reversed <swap|skip>
+                   read a reversed day 2 range such as 22-11 the right way
+                   round, or skip it, with a warning either way";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub days: Vec<u32>,
+    pub parts: Vec<Part>,
+    pub input: Option<Source>,
+    pub check: bool,
+    pub answers: Option<PathBuf>,
+    pub bench: Option<usize>,
+    pub bench_csv: PathBuf,
+    pub format: Format,
+    // set by the binaries of the days that take their own options
+    pub settings: Settings,
+}
+
+impl Options {
+    // Parse the arguments of the aoc runner, not including the program name
+    pub fn parse_runner(args: impl IntoIterator<Item = String>) -> Result<Options, Error> {
+        let mut args = args.into_iter();
+        if args.next().as_deref() != Some("run") {
+            return Err(Error::Usage("expected the run command".to_owned()));
+        }
+        let days = match args.next().as_deref() {
+            Some("all") => DAYS.iter().map(|day| day.number).collect(),
+            Some(day) => vec![parse_day(day)?],
+            None => return Err(Error::Usage("expected a day or all".to_owned())),
+        };
+        Self::parse_flags(days, args)
+    }
+
+    // Parse the arguments of a single day's binary, not including the program name
+    pub fn parse_day(day: u32, args: impl IntoIterator<Item = String>) -> Result<Options, Error> {
+        Self::parse_flags(vec![day], args.into_iter())
+    }
+
+    fn parse_flags(
+        days: Vec<u32>,
+        mut args: impl Iterator<Item = String>,
+    ) -> Result<Options, Error> {
+        let mut options = Options {
+            days,
+            parts: Part::ALL.to_vec(),
+            input: None,
+            check: false,
+            answers: None,
+            bench: None,
+            bench_csv: PathBuf::from("bench.csv"),
+            format: Format::Text,
+            settings: Settings::default(),
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| Error::Usage(format!("{} expects a value", arg)))
+            };
+            match arg.as_str() {
+                "--part" => {
+                    let part = value()?;
+                    let part = Part::parse(&part)
+                        .ok_or_else(|| Error::Usage(format!("unknown part {:?}", part)))?;
+                    options.parts = vec![part];
+                }
+                "--input" => options.input = Some(Source::from_arg(&value()?)),
+                "--check" => options.check = true,
+                "--answers" => {
+                    options.answers = Some(PathBuf::from(value()?));
+                    options.check = true;
+                }
+                "--bench" => {
+                    let n = value()?;
+                    let n = n
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| Error::Usage(format!("invalid iterations {:?}", n)))?;
+                    options.bench = Some(n);
+                }
+                "--bench-csv" => options.bench_csv = PathBuf::from(value()?),
+                "--format" => {
+                    options.format = match value()?.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        format => return Err(Error::Usage(format!("unknown format {:?}", format))),
+                    }
+                }
+                _ => return Err(Error::Usage(format!("unexpected argument {:?}", arg))),
+            }
+        }
+        if options.days.len() > 1 {
+            if options.input.is_some() {
+                return Err(Error::Usage("--input needs a single day".to_owned()));
+            }
+            if options.answers.is_some() {
+                return Err(Error::Usage("--answers needs a single day".to_owned()));
+            }
+        }
+        Ok(options)
+    }
+}
+
+// The options that only the day01 binary takes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DialOptions {
+    pub dial: Dial,
+    // whether --dial-size and --dial-start were given, rather than left as defaults
+    pub size_given: bool,
+    pub start_given: bool,
+    // print each step instead of the answers, drawing the dial after each when rendering
+    pub trace: bool,
+    pub render: bool,
+    // stream the input, resuming from and saving to a checkpoint file
+    pub checkpoint: Option<PathBuf>,
+}
+
+// Take --dial-size, --dial-start, --trace, --render and --checkpoint out of the arguments,
+// returning the options they describe and the arguments that are left
+pub fn split_dial_args(args: Vec<String>) -> Result<(DialOptions, Vec<String>), Error> {
+    let (mut size, mut start) = (Dial::SIZE, Dial::START);
+    let (mut size_given, mut start_given) = (false, false);
+    let (mut trace, mut render) = (false, false);
+    let mut checkpoint = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let slot = match arg.as_str() {
+            "--dial-size" => {
+                size_given = true;
+                &mut size
+            }
+            "--dial-start" => {
+                start_given = true;
+                &mut start
+            }
+            "--trace" => {
+                trace = true;
+                continue;
+            }
+            "--render" => {
+                (trace, render) = (true, true);
+                continue;
+            }
+            "--checkpoint" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| Error::Usage(format!("{} expects a value", arg)))?;
+                checkpoint = Some(PathBuf::from(path));
+                continue;
+            }
+            _ => {
+                rest.push(arg);
+                continue;
+            }
+        };
+        let value = args
+            .next()
+            .ok_or_else(|| Error::Usage(format!("{} expects a value", arg)))?;
+        *slot = value
+            .parse()
+            .map_err(|_| Error::Usage(format!("invalid {} {:?}", arg, value)))?;
+    }
+    if size <= 0 {
+        return Err(Error::Usage(format!("invalid --dial-size {}", size)));
+    }
+    let options = DialOptions {
+        dial: Dial::with_size(size, start),
+        size_given,
+        start_given,
+        trace,
+        render,
+        checkpoint,
+    };
+    Ok((options, rest))
+}
+
+// The options for the ids to check: the radix they are written in, 10 by default,
+// the rule for invalid ids to sum in place of the two parts, and what to do with
+// reversed ranges, which are swapped by default
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdOptions {
+    pub radix: u32,
+    pub rule: Option<String>,
+    pub reversed: Reversed,
+}
+
+// Take --radix, --rule and --reversed from the arguments, returning the others
+pub fn split_id_args(args: Vec<String>) -> Result<(IdOptions, Vec<String>), Error> {
+    let mut options = IdOptions {
+        radix: 10,
+        rule: None,
+        reversed: Reversed::Swap,
+    };
+    let mut rest = Vec::with_capacity(args.len());
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if !["--radix", "--rule", "--reversed"].contains(&arg.as_str()) {
+            rest.push(arg);
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| Error::Usage(format!("{} expects a value", arg)))?;
+        let invalid = || Error::Usage(format!("invalid {} {:?}", arg, value));
+        match arg.as_str() {
+            "--rule" => options.rule = Some(value.clone()),
+            "--reversed" => {
+                options.reversed = match value.as_str() {
+                    "swap" => Reversed::Swap,
+                    "skip" => Reversed::Skip,
+                    _ => return Err(invalid()),
+                }
+            }
+            _ => {
+                options.radix = value
+                    .parse()
+                    .ok()
+                    .filter(|radix| (2..=36).contains(radix))
+                    .ok_or_else(invalid)?
+            }
+        }
+    }
+    Ok((options, rest))
+}
+
+// Write a random input for a day to stdout
+#[derive(Debug, PartialEq)]
+pub struct Generate {
+    pub day: u32,
+    pub size: usize,
+    pub seed: Option<u64>,
+}
+
+impl Generate {
+    // Parse the arguments of aoc generate, not including the program name
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Generate, Error> {
+        let mut args = args.into_iter();
+        if args.next().as_deref() != Some("generate") {
+            return Err(Error::Usage("expected the generate command".to_owned()));
+        }
+        let day = args
+            .next()
+            .ok_or_else(|| Error::Usage("expected a day".to_owned()))?;
+        let mut generate = Generate {
+            day: parse_day(&day)?,
+            size: 100,
+            seed: None,
+        };
+        while let Some(arg) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| Error::Usage(format!("{} expects a value", arg)))?;
+            let invalid = || Error::Usage(format!("invalid {} {:?}", arg, value));
+            match arg.as_str() {
+                "--size" => generate.size = value.parse().map_err(|_| invalid())?,
+                "--seed" => generate.seed = Some(value.parse().map_err(|_| invalid())?),
+                _ => return Err(Error::Usage(format!("unexpected argument {:?}", arg))),
+            }
+        }
+        Ok(generate)
+    }
+
+    pub fn run(&self) {
+        let seed = self.seed.unwrap_or_else(|| {
+            let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH);
+            let seed = now.map_or(0, |t| t.as_nanos() as u64);
+            eprintln!("seed {}", seed);
+            seed
+        });
+        let day = Day::find(self.day).expect("days are checked when parsing options");
+        print!("{}", day.generate(&mut Rng::new(seed), self.size));
+    }
+}
+
+fn parse_day(input: &str) -> Result<u32, Error> {
+    input
+        .parse()
+        .ok()
+        .filter(|number| Day::find(*number).is_some())
+        .ok_or_else(|| Error::Usage(format!("unknown day {:?}", input)))
+}
+
+// Solve and print every selected day and part, returning false if any of them failed
+pub fn run(options: &Options) -> bool {
+    let mut timings = Vec::new();
+    let ok = options.days.iter().fold(true, |ok, &number| {
+        let day = Day::find(number).expect("days are checked when parsing options");
+        run_day(options, day, &mut timings) && ok
+    });
+    if options.bench.is_none() {
+        return ok;
+    }
+    let csv = std::iter::once(CSV_HEADER.to_owned())
+        .chain(timings)
+        .map(|row| row + "\n")
+        .collect::<String>();
+    match std::fs::write(&options.bench_csv, csv) {
+        Ok(()) => ok,
+        Err(e) => {
+            eprintln!("{}: {}", options.bench_csv.display(), e);
+            false
+        }
+    }
+}
+
+// Solve one day, adding a row to timings for each stage when benchmarking
+fn run_day(options: &Options, day: &Day, timings: &mut Vec<String>) -> bool {
+    let source = options.input.clone().unwrap_or_else(|| day.input());
+    let text = match source.read_to_string() {
+        Ok(text) => text,
+        Err(e) => {
+            print_error(options.format, day.number, &source, &e.into());
+            return false;
+        }
+    };
+    let answers = match day.solve(&text, &options.parts, &options.settings) {
+        Ok(Solved {
+            warnings, answers, ..
+        }) => {
+            for warning in warnings {
+                print_warning(options.format, day.number, &warning);
+            }
+            answers
+        }
+        Err(e) => {
+            print_error(options.format, day.number, &source, &e);
+            return false;
+        }
+    };
+    let expected = if options.check {
+        let path = options
+            .answers
+            .clone()
+            .unwrap_or_else(|| day.data_path("answers.txt"));
+        match Answers::load(&path) {
+            Ok(expected) => Some(expected),
+            Err(e) => {
+                print_error(options.format, day.number, &path.display(), &e);
+                return false;
+            }
+        }
+    } else {
+        None
+    };
+    let failures = answers
+        .iter()
+        .filter(|answer| !print_answer(options.format, day.number, answer, expected.as_ref()))
+        .count();
+    if let Some(iterations) = options.bench {
+        match bench(day, &text, &options.parts, &options.settings, iterations) {
+            Ok(stats) => {
+                for (stage, stats) in stats {
+                    print_stats(options.format, day.number, stage, &stats);
+                    timings.push(csv_row(day.number, stage, &stats));
+                }
+            }
+            Err(e) => {
+                print_error(options.format, day.number, &source, &e);
+                return false;
+            }
+        }
+    }
+    failures == 0
+}
+
+// Print one answer, checking it against the expected answers when given
+fn print_answer(format: Format, number: u32, answer: &Answer, expected: Option<&Answers>) -> bool {
+    let Answer {
+        part,
+        value,
+        details,
+        elapsed,
+    } = answer;
+    let (note, ok) = match (value, expected.map(|answers| answers.get(*part))) {
+        (Ok(_), None) => (None, true),
+        (Ok(_), Some(None)) => (Some("unchecked".to_owned()), true),
+        (Ok(value), Some(Some(known))) if value == known => (Some("ok".to_owned()), true),
+        (Ok(_), Some(Some(known))) => (Some(format!("expected {}", known)), false),
+        // a part that is not solved yet fails only when its answer is known
+        (Err(Error::Unsolved), Some(Some(known))) => (Some(format!("expected {}", known)), false),
+        (Err(Error::Unsolved), _) => (None, true),
+        (Err(_), _) => (None, false),
+    };
+    match (format, value) {
+        (Format::Text, Ok(value)) => {
+            let details: String = details
+                .iter()
+                .map(|(name, value)| format!(", {} {}", name, value))
+                .collect();
+            match note {
+                Some(note) => println!(
+                    "day {:02} part {}: {}{} ({})",
+                    number, part, value, details, note
+                ),
+                None => println!("day {:02} part {}: {}{}", number, part, value, details),
+            }
+        }
+        (Format::Text, Err(Error::Unsolved)) => match note {
+            Some(note) => println!("day {:02} part {}: unsolved ({})", number, part, note),
+            None => println!("day {:02} part {}: unsolved", number, part),
+        },
+        (Format::Text, Err(e)) => eprintln!("day {:02} part {}: {}", number, part, e),
+        (Format::Json, _) => {
+            let error = match value {
+                Ok(_) if ok => None,
+                Ok(_) => note,
+                Err(e) => match note {
+                    Some(note) => Some(format!("{}, {}", e, note)),
+                    None => Some(e.to_string()),
+                },
+            };
+            let mut fields = vec![
+                ("day", Value::Number(number.into())),
+                ("part", Value::Number(part.number().into())),
+                (
+                    "answer",
+                    value.as_deref().map_or(Value::Null, Value::String),
+                ),
+                ("elapsed_ns", Value::Number(elapsed.as_nanos())),
+                ("error", error.as_deref().map_or(Value::Null, Value::String)),
+            ];
+            fields.extend(
+                details
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), Value::String(value))),
+            );
+            println!("{}", json::object(&fields));
+        }
+    }
+    ok
+}
+
+// Print something odd about a day's input that its answers work around
+fn print_warning(format: Format, number: u32, warning: &str) {
+    match format {
+        Format::Text => eprintln!("day {:02}: warning: {}", number, warning),
+        Format::Json => println!(
+            "{}",
+            json::object(&[
+                ("day", Value::Number(number.into())),
+                ("warning", Value::String(warning)),
+            ])
+        ),
+    }
+}
+
+// Print a failure of a whole day, such as unreadable or invalid input
+fn print_error(format: Format, number: u32, context: &dyn Display, e: &Error) {
+    match format {
+        Format::Text => eprintln!("day {:02}: {}: {}", number, context, e),
+        Format::Json => println!(
+            "{}",
+            json::object(&[
+                ("day", Value::Number(number.into())),
+                ("part", Value::Null),
+                ("answer", Value::Null),
+                ("elapsed_ns", Value::Null),
+                ("error", Value::String(&format!("{}: {}", context, e))),
+            ])
+        ),
+    }
+}
+
+fn print_stats(format: Format, number: u32, stage: Stage, stats: &Stats) {
+    match format {
+        Format::Text => println!(
+            "day {:02} {} x{}: min {:?}, median {:?}, max {:?}",
+            number, stage, stats.iterations, stats.min, stats.median, stats.max
+        ),
+        Format::Json => println!(
+            "{}",
+            json::object(&[
+                ("day", Value::Number(number.into())),
+                ("stage", Value::String(&stage.to_string())),
+                ("iterations", Value::Number(stats.iterations as u128)),
+                ("min_ns", Value::Number(stats.min.as_nanos())),
+                ("median_ns", Value::Number(stats.median.as_nanos())),
+                ("max_ns", Value::Number(stats.max.as_nanos())),
+            ])
+        ),
+    }
+}
+
+fn exit(options: Result<Options, Error>) -> ExitCode {
+    match options {
+        Ok(options) if run(&options) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => usage(e),
+    }
+}
+
+pub fn usage(e: Error) -> ExitCode {
+    eprintln!("{}\n{}", e, USAGE);
+    eprintln!(
+        "environment:\n  {}   data directory, data if unset",
+        DATA_DIR_VAR
+    );
+    ExitCode::from(2)
+}
+
+// Entry point of the aoc runner
+pub fn runner_main() -> ExitCode {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("generate") => match Generate::parse(args) {
+            Ok(generate) => {
+                generate.run();
+                ExitCode::SUCCESS
+            }
+            Err(e) => usage(e),
+        },
+        _ => exit(Options::parse_runner(args)),
+    }
+}
+
+// Entry point of each dayNN binary
+pub fn day_main(day: u32) -> ExitCode {
+    day_main_with(day, std::env::args().skip(1), Settings::default())
+}
+
+// Entry point of a dayNN binary that has taken its own arguments out first, as the
+// settings for reading its input
+pub fn day_main_with(
+    day: u32,
+    args: impl IntoIterator<Item = String>,
+    settings: Settings,
+) -> ExitCode {
+    exit(Options::parse_day(day, args).map(|options| Options {
+        settings,
+        ..options
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_parse_runner() {
+        let options = Options::parse_runner(args("run 5 --part 2 --input in.txt")).unwrap();
+        assert_eq!(
+            options,
+            Options {
+                days: vec![5],
+                parts: vec![Part::Two],
+                input: Some(Source::from_arg("in.txt")),
+                check: false,
+                answers: None,
+                bench: None,
+                bench_csv: PathBuf::from("bench.csv"),
+                format: Format::Text,
+                settings: Settings::default(),
+            }
+        );
+        let options = Options::parse_day(2, args("--input -")).unwrap();
+        assert_eq!(options.input, Some(Source::Stdin));
+        let options = Options::parse_runner(args("run all")).unwrap();
+        assert_eq!(options.days, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(options.parts, Part::ALL);
+        let options = Options::parse_runner(args("run all --check")).unwrap();
+        assert!(options.check);
+        let options = Options::parse_day(4, args("--answers known.txt")).unwrap();
+        assert_eq!(options.answers, Some(PathBuf::from("known.txt")));
+        assert!(options.check);
+        let options = Options::parse_runner(args("run all --bench 10 --bench-csv b.csv")).unwrap();
+        assert_eq!(options.bench, Some(10));
+        assert_eq!(options.bench_csv, PathBuf::from("b.csv"));
+        let options = Options::parse_day(8, args("--format json")).unwrap();
+        assert_eq!(options.format, Format::Json);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Options::parse_runner(args("solve 1")).is_err());
+        assert!(Options::parse_runner(args("run 10")).is_err());
+        assert!(Options::parse_runner(args("run 1 --part 3")).is_err());
+        assert!(Options::parse_runner(args("run all --input in.txt")).is_err());
+        assert!(Options::parse_runner(args("run all --answers known.txt")).is_err());
+        assert!(Options::parse_day(3, args("--input")).is_err());
+        assert!(Options::parse_day(3, args("--bench 0")).is_err());
+        assert!(Options::parse_day(3, args("--format yaml")).is_err());
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        let generate = Generate::parse(args("generate 4 --size 50 --seed 9")).unwrap();
+        assert_eq!(
+            generate,
+            Generate {
+                day: 4,
+                size: 50,
+                seed: Some(9),
+            }
+        );
+        assert_eq!(Generate::parse(args("generate 2")).unwrap().seed, None);
+        assert!(Generate::parse(args("generate 12")).is_err());
+        assert!(Generate::parse(args("generate 1 --size many")).is_err());
+    }
+
+    #[test]
+    fn test_split_dial_args() {
+        let (options, rest) =
+            split_dial_args(args("--part 2 --dial-size 1000 --dial-start 999")).unwrap();
+        assert_eq!(options.dial, Dial::with_size(1000, 999));
+        assert!(!options.trace);
+        assert!(options.size_given && options.start_given);
+        assert_eq!(rest, ["--part", "2"]);
+        let (options, _) = split_dial_args(args("--render")).unwrap();
+        assert_eq!(options.dial, Dial::default());
+        assert!(!options.size_given && !options.start_given);
+        assert!(options.trace && options.render);
+        let (options, _) = split_dial_args(args("--checkpoint day01.state")).unwrap();
+        assert_eq!(options.checkpoint, Some(PathBuf::from("day01.state")));
+        assert!(split_dial_args(args("--dial-size 0")).is_err());
+        assert!(split_dial_args(args("--dial-start")).is_err());
+    }
+
+    #[test]
+    fn test_print_answer() {
+        let answer = |part, value| Answer {
+            part,
+            value,
+            details: Vec::new(),
+            elapsed: std::time::Duration::ZERO,
+        };
+        let known = Answers::parse("part1 = 3").unwrap();
+        let check = |value| print_answer(Format::Text, 1, &answer(Part::One, value), Some(&known));
+        assert!(check(Ok("3".to_owned())));
+        assert!(!check(Ok("4".to_owned())));
+        assert!(!check(Err(Error::NoAnswer)));
+        // a known answer fails when the part is not solved, but an unknown one does not
+        assert!(!check(Err(Error::Unsolved)));
+        let unsolved = answer(Part::Two, Err(Error::Unsolved));
+        assert!(print_answer(Format::Json, 1, &unsolved, Some(&known)));
+        assert!(print_answer(Format::Text, 1, &unsolved, None));
+    }
+
+    #[test]
+    fn test_split_id_args() {
+        let (options, rest) = split_id_args(args("--radix 16 --input - --rule twice|any")).unwrap();
+        assert_eq!(
+            (options.radix, options.rule.as_deref()),
+            (16, Some("twice|any"))
+        );
+        assert_eq!(rest, ["--input", "-"]);
+        assert_eq!(options.reversed, Reversed::Swap);
+        let (options, _) = split_id_args(args("--reversed skip")).unwrap();
+        assert_eq!(options.reversed, Reversed::Skip);
+        assert!(split_id_args(args("--reversed keep")).is_err());
+        assert!(split_id_args(args("--radix 37")).is_err());
+        assert!(split_id_args(args("--radix")).is_err());
+    }
+}
@@ -0,0 +1,1552 @@
+// Advent of Code 2025 Day 1
+// A. Drew
+
+use crate::parse::{parse_all, IResult};
+use crate::rng::Rng;
+use crate::{Error, ParseError, Settings, Solution};
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display};
+use std::io::BufRead;
+use std::iter::zip;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+// Positions, rotations and counts of clicks of the puzzle's dial
+pub type Int = isize;
+
+// The integers a dial can count in. The puzzle's dial uses Int, and WideDial i128
+// for rotations and counts too large for that.
+pub trait DialInt:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    // x as this type, which always fits
+    fn from_isize(x: isize) -> Self;
+    fn from_usize(x: usize) -> Option<Self>;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn is_negative(self) -> bool;
+}
+
+macro_rules! dial_int {
+    ($($t:ty),*) => {
+        $(impl DialInt for $t {
+            fn from_isize(x: isize) -> Self {
+                x as $t
+            }
+
+            fn from_usize(x: usize) -> Option<Self> {
+                <$t>::try_from(x).ok()
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+
+            fn is_negative(self) -> bool {
+                <$t>::is_negative(self)
+            }
+        })*
+    };
+}
+
+dial_int!(isize, i128);
+
+const OVERFLOW: &str = "too many clicks to count";
+
+pub fn parse_rotation(line: &str) -> Result<Int, ParseError> {
+    use nom::{combinator::eof, error::context, sequence::terminated};
+    parse_all(line, terminated(rotation, context("a digit", eof)))
+}
+
+fn rotation<I: DialInt>(input: &str) -> IResult<'_, I> {
+    use nom::{
+        character::complete::{char, digit1},
+        combinator::cut,
+        error::context,
+        Parser,
+    };
+    let direction = context("L or R", char('L').map(|_| -1).or(char('R').map(|_| 1)));
+    let magnitude = context("a number", digit1.map_res(str::parse::<I>));
+    // once there is a direction it must have a number
+    direction
+        .and(cut(magnitude))
+        .map(|(d, m): (isize, I)| I::from_isize(d) * m)
+        .parse(input)
+}
+
+// A line of a rotation log: moves separated by commas or spaces, where a move is a
+// rotation that may be repeated like 3xL25, then an optional # comment. Returns
+// each move as a count and a rotation.
+pub fn parse_moves<I: DialInt>(line: &str) -> Result<Vec<(usize, I)>, ParseError> {
+    use nom::{
+        branch::alt,
+        character::complete::{char, digit1, space0, space1},
+        combinator::{cut, eof, opt, peek, rest, verify},
+        error::context,
+        multi::many0,
+        sequence::{preceded, terminated},
+        Parser,
+    };
+    let count = || {
+        let count = verify(digit1.map_res(str::parse::<usize>), |n| *n > 0);
+        // once there is a number it must be a count followed by an x
+        let repeat = preceded(
+            peek(digit1),
+            cut(terminated(
+                context("a count of at least 1", count),
+                char('x'),
+            )),
+        );
+        opt(repeat).and(rotation).map(|(n, r)| (n.unwrap_or(1), r))
+    };
+    let after_comma = preceded((space0, char(','), space0), cut(count()));
+    let after_space = preceded(space1, count());
+    let moves = count()
+        .and(many0(alt((after_comma, after_space))))
+        .map(|(first, mut rest)| {
+            rest.insert(0, first);
+            rest
+        });
+    // a line may have no moves at all
+    let none = peek(alt((eof, char('#').map(|_| "#")))).map(|_| Vec::new());
+    let line_end = (space0, opt(preceded(char('#'), rest)));
+    let moves = preceded(space0, alt((none, moves)));
+    parse_all(
+        line,
+        terminated(
+            moves,
+            terminated(line_end, context("',', a space or '#'", eof)),
+        ),
+    )
+}
+
+// Every move of a rotation log in order, kept as a count and a rotation so that a
+// long run of repeats takes no more room than one rotation
+pub fn parse_log<I: DialInt>(text: &str) -> Result<Vec<(usize, I)>, ParseError> {
+    let mut runs = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        runs.extend(parse_moves(line).map_err(|e| e.on_line(i + 1))?);
+    }
+    Ok(runs)
+}
+
+// The rotations of the runs one at a time, spelled out as they are needed
+pub fn expand<I: Copy>(runs: &[(usize, I)]) -> impl Iterator<Item = I> + '_ {
+    runs.iter()
+        .flat_map(|(count, rotation)| std::iter::repeat_n(*rotation, *count))
+}
+
+// A rotation of one dial of a lock, such as 2:L30. Dials are numbered from 1 and
+// a rotation without a number turns the first dial. Returns the index of the dial.
+pub fn parse_lock_rotation(line: &str) -> Result<(usize, Int), ParseError> {
+    use nom::{
+        character::complete::{char, digit1},
+        combinator::{cut, eof, opt, peek, verify},
+        error::context,
+        sequence::{preceded, terminated},
+        Parser,
+    };
+    let number = verify(digit1.map_res(str::parse::<usize>), |n| *n > 0);
+    // once there is a number it must be a dial followed by a colon
+    let dial = opt(preceded(
+        peek(digit1),
+        cut(terminated(context("a dial number", number), char(':'))),
+    ));
+    let rotation = dial.and(rotation).map(|(n, r)| (n.map_or(0, |n| n - 1), r));
+    parse_all(line, terminated(rotation, context("a digit", eof)))
+}
+
+// A dial with positions 0 to size - 1, counting in Int unless it is a WideDial
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dial<I = Int> {
+    position: I,
+    size: I,
+}
+
+pub type WideDial = Dial<i128>;
+
+impl Dial {
+    pub const SIZE: Int = 100;
+    pub const START: Int = 50;
+
+    // A dial of the usual size
+    pub fn new(position: Int) -> Self {
+        Self::with_size(Self::SIZE, position)
+    }
+}
+
+impl<I: DialInt> Dial<I> {
+    // A dial with positions 0 to size - 1, which must be positive
+    pub fn with_size(size: I, start: I) -> Self {
+        assert!(
+            size > I::from_isize(0),
+            "a dial needs at least one position"
+        );
+        Dial {
+            position: start.rem_euclid(size),
+            size,
+        }
+    }
+
+    pub fn read(&self) -> I {
+        self.position
+    }
+
+    pub fn size(&self) -> I {
+        self.size
+    }
+
+    pub fn spin(&mut self, rotation: I) -> I {
+        self.checked_spin(rotation).expect(OVERFLOW)
+    }
+
+    // Spin the dial, or leave it be and return None if the clicks onto zero do not
+    // fit in an I
+    pub fn checked_spin(&mut self, rotation: I) -> Option<I> {
+        let clicks = self.checked_clicks_at(I::from_isize(0), rotation)?;
+        self.turn(rotation);
+        Some(clicks)
+    }
+
+    // Move the dial by the rotation without counting any clicks
+    fn turn(&mut self, rotation: I) {
+        self.position = add_mod(self.position, rotation.rem_euclid(self.size), self.size);
+    }
+
+    // Spin the dial by the rotation count times over, returning how many of those
+    // rotations stopped at zero and how many clicks onto zero there were. Takes
+    // O(log size) steps however large the count, and never needs the total rotation.
+    // Leaves the dial be and returns None if the count or either answer does not
+    // fit in an I.
+    pub fn checked_spin_run(&mut self, count: usize, rotation: I) -> Option<(I, I)> {
+        let zero = I::from_isize(0);
+        let count = I::from_usize(count)?;
+        // after k rotations the dial is at position + k * step, so it stops at zero
+        // when k * step = -position modulo size, which has a solution only if their
+        // common divisor g divides -position, and then one every size / g rotations
+        let (size, step) = (self.size, rotation.rem_euclid(self.size));
+        let target = (-self.position).rem_euclid(size);
+        let g = gcd(step, size);
+        let landings = if target % g != zero {
+            zero
+        } else {
+            let period = size / g;
+            let inverse = inverse_mod(step / g % period, period);
+            let first = mul_mod(target / g % period, inverse, period);
+            let first = if first == zero { period } else { first };
+            if first > count {
+                zero
+            } else {
+                (count - first) / period + I::from_isize(1)
+            }
+        };
+        // each rotation makes its full turns, each onto zero once, then the rest of
+        // it, which measured from zero in the direction of travel clicks onto zero
+        // each time the rests add up to another turn
+        let (turns, rest, from) = if rotation.is_negative() {
+            let from = (-self.position).rem_euclid(size);
+            ((rotation / size).checked_neg()?, -(rotation % size), from)
+        } else {
+            (rotation / size, rotation % size, self.position)
+        };
+        let (rest_turns, rest) = mul_div_mod(count, rest, size)?;
+        let clicks = count
+            .checked_mul(turns)?
+            .checked_add(rest_turns)?
+            .checked_add(I::from_isize((from >= size - rest) as isize))?;
+        self.position = add_mod(self.position, mul_mod(count % size, step, size), size);
+        Some((landings, clicks))
+    }
+
+    // How many clicks of the rotation would leave the dial pointing at target
+    pub fn count_clicks_at(&self, target: I, rotation: I) -> I {
+        self.checked_clicks_at(target, rotation).expect(OVERFLOW)
+    }
+
+    // Count the clicks without overflowing along the way, so None means the count
+    // itself does not fit in an I
+    pub fn checked_clicks_at(&self, target: I, rotation: I) -> Option<I> {
+        // measure from the target so that it sits at zero
+        let position = (self.position - target.rem_euclid(self.size)).rem_euclid(self.size);
+        let (turns, rest, position) = if rotation.is_negative() {
+            // translate to an equivalent positive rotation
+            let position = (-position).rem_euclid(self.size);
+            (
+                (rotation / self.size).checked_neg()?,
+                -(rotation % self.size),
+                position,
+            )
+        } else {
+            (rotation / self.size, rotation % self.size, position)
+        };
+        // a full turn passes zero once, and the rest passes it if it goes round
+        turns.checked_add(I::from_isize((rest >= self.size - position) as isize))
+    }
+}
+
+fn gcd<I: DialInt>(a: I, b: I) -> I {
+    if b == I::from_isize(0) {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// a + b modulo m, for a and b from 0 to m - 1, without going past m
+fn add_mod<I: DialInt>(a: I, b: I, m: I) -> I {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+// a * b modulo m by doubling and adding, so that nothing goes past m
+fn mul_mod<I: DialInt>(a: I, b: I, m: I) -> I {
+    let (zero, two) = (I::from_isize(0), I::from_isize(2));
+    let (mut a, mut b, mut product) = (a, b, zero);
+    while b > zero {
+        if b % two != zero {
+            product = add_mod(product, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b / two;
+    }
+    product
+}
+
+// a * b divided by m and modulo m, for b from 0 to m - 1, by doubling and adding
+// with each value kept as a quotient and a remainder. None if the quotient does not
+// fit in an I, which is the only value that can grow past m.
+fn mul_div_mod<I: DialInt>(a: I, b: I, m: I) -> Option<(I, I)> {
+    let (zero, two) = (I::from_isize(0), I::from_isize(2));
+    let carry = |x: I, y: I| I::from_isize((x >= m - y) as isize);
+    // b times a power of two, and the product so far
+    let (mut a, mut addend, mut product) = (a, (zero, b), (zero, zero));
+    while a > zero {
+        if a % two != zero {
+            let turns = product.0.checked_add(addend.0)?;
+            product = (
+                turns.checked_add(carry(product.1, addend.1))?,
+                add_mod(product.1, addend.1, m),
+            );
+        }
+        a = a / two;
+        // only double while a is left, so that the addend never passes the product
+        if a > zero {
+            let turns = addend.0.checked_mul(two)?;
+            addend = (
+                turns.checked_add(carry(addend.1, addend.1))?,
+                add_mod(addend.1, addend.1, m),
+            );
+        }
+    }
+    Some(product)
+}
+
+// The x from 0 to m - 1 with a * x = 1 modulo m, for a coprime to m, by the
+// extended Euclidean algorithm with the coefficients kept modulo m
+fn inverse_mod<I: DialInt>(a: I, m: I) -> I {
+    let zero = I::from_isize(0);
+    let (mut r0, mut r1) = (m, a);
+    let (mut t0, mut t1) = (zero, I::from_isize(1) % m);
+    while r1 != zero {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        let qt = mul_mod(q % m, t1, m);
+        (t0, t1) = (t1, add_mod(t0, (m - qt) % m, m));
+    }
+    t0
+}
+
+impl<I: DialInt> Default for Dial<I> {
+    fn default() -> Self {
+        Self::with_size(I::from_isize(Dial::SIZE), I::from_isize(Dial::START))
+    }
+}
+
+// Used to iterate over the positions and clicks from the initial dial using the input rotations
+pub fn process(
+    dial: Dial,
+    rotations: impl IntoIterator<Item = Int>,
+) -> impl Iterator<Item = (Int, Int)> {
+    rotations.into_iter().scan(dial, |dial, rotation| {
+        let clicks = dial.spin(rotation);
+        Some((dial.read(), clicks))
+    })
+}
+
+// How often the dial visited a position: the rotations that stopped there, and the
+// clicks that left it pointing there, including the last click of those rotations
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Visits {
+    pub landings: Int,
+    pub clicks: Int,
+}
+
+// Count the visits to each target, taking the targets modulo the size of the dial,
+// checking that the counts fit
+pub fn count_visits(
+    mut dial: Dial,
+    rotations: impl IntoIterator<Item = Int>,
+    targets: &[Int],
+) -> Result<BTreeMap<Int, Visits>, Error> {
+    let mut visits: BTreeMap<_, Visits> = targets
+        .iter()
+        .map(|target| (target.rem_euclid(dial.size()), Visits::default()))
+        .collect();
+    for rotation in rotations {
+        for (target, visits) in visits.iter_mut() {
+            let clicks = dial.checked_clicks_at(*target, rotation);
+            visits.clicks = clicks
+                .and_then(|clicks| visits.clicks.checked_add(clicks))
+                .ok_or(Error::Overflow)?;
+        }
+        dial.checked_spin(rotation).ok_or(Error::Overflow)?;
+        if let Some(visits) = visits.get_mut(&dial.read()) {
+            visits.landings = visits.landings.checked_add(1).ok_or(Error::Overflow)?;
+        }
+    }
+    Ok(visits)
+}
+
+// The visits to every position of the dial, indexed by position
+pub fn histogram(
+    dial: Dial,
+    rotations: impl IntoIterator<Item = Int>,
+) -> Result<Vec<Visits>, Error> {
+    let positions: Vec<_> = (0..dial.size()).collect();
+    Ok(count_visits(dial, rotations, &positions)?
+        .into_values()
+        .collect())
+}
+
+// Makes a dial turn whenever another dial clicks past a notch, between notch - 1 and
+// notch, in either direction. Each such click turns the driven dial one click the
+// same way, which is how an odometer carries and borrows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Coupling {
+    pub from: usize,
+    pub notch: Int,
+    pub to: usize,
+}
+
+// Several dials on one spindle, where turning one dial can drive the ones after it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lock {
+    dials: Vec<Dial>,
+    couplings: Vec<Coupling>,
+}
+
+impl Lock {
+    // Couplings must join existing dials and only drive later dials
+    pub fn new(dials: Vec<Dial>, couplings: Vec<Coupling>) -> Self {
+        for c in &couplings {
+            assert!(
+                c.from < c.to && c.to < dials.len(),
+                "a coupling must drive a later dial of the lock"
+            );
+        }
+        Lock { dials, couplings }
+    }
+
+    // Dials that each carry into the next as they click onto zero, like an odometer
+    pub fn odometer(dials: usize, size: Int) -> Self {
+        let couplings = (1..dials)
+            .map(|to| Coupling {
+                from: to - 1,
+                notch: 0,
+                to,
+            })
+            .collect();
+        Self::new(vec![Dial::with_size(size, 0); dials], couplings)
+    }
+
+    pub fn dials(&self) -> &[Dial] {
+        &self.dials
+    }
+
+    // Parse lock rotations, one per line, checking that each dial exists
+    pub fn parse_rotations(&self, text: &str) -> Result<Vec<(usize, Int)>, ParseError> {
+        text.lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let (dial, rotation) = parse_lock_rotation(line).map_err(|e| e.on_line(i + 1))?;
+                if dial < self.dials.len() {
+                    Ok((dial, rotation))
+                } else {
+                    let expected = format!("a dial from 1 to {}", self.dials.len());
+                    Err(ParseError::at_line(i + 1, line, 0, expected))
+                }
+            })
+            .collect()
+    }
+
+    // Turn a dial, and any dials it drives, returning how many times each dial
+    // clicked onto zero. A dial driven by several others turns by the total. If
+    // any count does not fit the lock is left as it was.
+    pub fn spin(&mut self, dial: usize, rotation: Int) -> Result<Vec<Int>, Error> {
+        let mut dials = self.dials.clone();
+        let mut rotations = vec![0; dials.len()];
+        rotations[dial] = rotation;
+        // couplings only drive later dials, so one pass in order settles them all
+        let clicks = (0..dials.len())
+            .map(|i| {
+                let rotation = rotations[i];
+                for c in self.couplings.iter().filter(|c| c.from == i) {
+                    // turning backwards crosses the notch on the way to notch - 1
+                    let target = c.notch - rotation.is_negative() as Int;
+                    let clicks = dials[i].checked_clicks_at(target, rotation);
+                    rotations[c.to] = clicks
+                        .and_then(|clicks| rotations[c.to].checked_add(rotation.signum() * clicks))
+                        .ok_or(Error::Overflow)?;
+                }
+                dials[i].checked_spin(rotation).ok_or(Error::Overflow)
+            })
+            .collect::<Result<_, _>>()?;
+        self.dials = dials;
+        Ok(clicks)
+    }
+
+    // Apply the rotations in order, returning the total zero clicks of each dial
+    pub fn run(
+        &mut self,
+        rotations: impl IntoIterator<Item = (usize, Int)>,
+    ) -> Result<Vec<Int>, Error> {
+        rotations
+            .into_iter()
+            .try_fold(vec![0; self.dials.len()], |totals, (dial, rotation)| {
+                let clicks = self.spin(dial, rotation)?;
+                zip(totals, clicks)
+                    .map(|(a, b)| a.checked_add(b).ok_or(Error::Overflow))
+                    .collect()
+            })
+    }
+}
+
+// One rotation of the dial, with the clicks of it that left the dial at zero,
+// counting the clicks of the rotation from 1. Those come once a turn, so rather
+// than list them this keeps how many there were, the first, and the clicks from
+// one to the next, which is the size of the dial.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub rotation: Int,
+    pub start: Int,
+    pub end: Int,
+    pub zero_clicks: usize,
+    pub first_zero_click: usize,
+    pub spacing: usize,
+}
+
+impl Step {
+    // How many of the zero clicks Display lists
+    pub const SHOWN: usize = 5;
+
+    pub fn zero_click_numbers(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.zero_clicks).map(|i| self.first_zero_click + i * self.spacing)
+    }
+}
+
+impl std::fmt::Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {}, zero clicks {}",
+            format_rotation(self.rotation),
+            self.start,
+            self.end,
+            self.zero_clicks
+        )?;
+        if self.zero_clicks > 0 {
+            let clicks: Vec<_> = self
+                .zero_click_numbers()
+                .take(Self::SHOWN)
+                .map(|click| click.to_string())
+                .collect();
+            write!(f, " (at {}", clicks.join(", "))?;
+            if self.zero_clicks > Self::SHOWN {
+                write!(f, " and {} more", self.zero_clicks - Self::SHOWN)?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+// Both passwords, the landings on zero and the clicks onto zero, for every start
+// position of a dial of the given size, indexed by start. A dial that starts at s
+// points at zero whenever s plus the total rotation so far is a multiple of size,
+// so one pass over the rotations tallies, for each residue of that total, how
+// often the rotations stop at it and click through it.
+pub fn passwords_by_start(
+    size: Int,
+    rotations: impl IntoIterator<Item = Int>,
+) -> Result<Vec<(Int, Int)>, Error> {
+    assert!(size > 0, "a dial needs at least one position");
+    let n = size as usize;
+    let mut landings = vec![0; n];
+    // clicks through every residue, plus a difference array of the partial turns
+    let mut full_turns = 0;
+    let mut partial = vec![0; n + 1];
+    // the total rotation so far, modulo size
+    let mut total = 0;
+    for rotation in rotations {
+        // the totals clicked through, which end at the new total
+        let low = if rotation.is_negative() {
+            total + rotation.rem_euclid(size)
+        } else {
+            total + 1
+        };
+        let clicks = rotation.checked_abs().ok_or(Error::Overflow)?;
+        full_turns = (clicks / size)
+            .checked_add(full_turns)
+            .ok_or(Error::Overflow)?;
+        let (first, rest) = ((low % size) as usize, (clicks % size) as usize);
+        partial[first] += 1;
+        if first + rest <= n {
+            partial[first + rest] -= 1;
+        } else {
+            partial[n] -= 1;
+            partial[0] += 1;
+            partial[first + rest - n] -= 1;
+        }
+        total = (total + rotation.rem_euclid(size)) % size;
+        let landed = &mut landings[total as usize];
+        *landed = Int::checked_add(*landed, 1).ok_or(Error::Overflow)?;
+    }
+    let through: Vec<_> = partial[..n]
+        .iter()
+        .scan(Some(full_turns), |sum, x| {
+            *sum = sum.and_then(|sum| sum.checked_add(*x));
+            Some(*sum)
+        })
+        .collect::<Option<_>>()
+        .ok_or(Error::Overflow)?;
+    Ok((0..size)
+        .map(|start| {
+            let residue = (-start).rem_euclid(size) as usize;
+            (landings[residue], through[residue])
+        })
+        .collect())
+}
+
+// Where a stream of rotations has got to, enough to carry on from later. It is
+// saved as a single line such as
+//
+//   position=82 size=100 landings=3 clicks=6 lines=10
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub dial: Dial,
+    // rotations that stopped at zero and clicks onto zero, the two passwords
+    pub landings: Int,
+    pub clicks: Int,
+    // lines read so far, so that line numbers carry on across chunks
+    pub lines: usize,
+}
+
+impl Checkpoint {
+    pub fn new(dial: Dial) -> Self {
+        Checkpoint {
+            dial,
+            landings: 0,
+            clicks: 0,
+            lines: 0,
+        }
+    }
+
+    // Process every line of the reader in constant memory. Lines that are not
+    // moves are passed to skipped and otherwise ignored, so that one bad line
+    // does not lose the rest. Each chunk should end at the end of a line.
+    pub fn feed(
+        &mut self,
+        mut reader: impl BufRead,
+        mut skipped: impl FnMut(ParseError),
+    ) -> Result<(), Error> {
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 0 {
+            let number = self.lines + 1;
+            match parse_moves(line.trim_end_matches(['\r', '\n'])) {
+                Ok(moves) => {
+                    // a line that overflows leaves the checkpoint where it was
+                    let mut next = Checkpoint {
+                        lines: number,
+                        ..*self
+                    };
+                    for (count, rotation) in moves {
+                        let (landings, clicks) = next
+                            .dial
+                            .checked_spin_run(count, rotation)
+                            .ok_or(Error::Overflow)?;
+                        next.landings =
+                            next.landings.checked_add(landings).ok_or(Error::Overflow)?;
+                        next.clicks = next.clicks.checked_add(clicks).ok_or(Error::Overflow)?;
+                    }
+                    *self = next;
+                }
+                Err(e) => {
+                    self.lines = number;
+                    skipped(e.on_line(number));
+                }
+            }
+            line.clear();
+        }
+        Ok(())
+    }
+
+    pub fn parse(text: &str) -> Result<Checkpoint, ParseError> {
+        const KEYS: [&str; 5] = ["position", "size", "landings", "clicks", "lines"];
+        let mut values = [None; 5];
+        for field in text.split_whitespace() {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| ParseError::at(text, &field[field.len()..], "'='"))?;
+            let slot = KEYS
+                .iter()
+                .position(|k| *k == key)
+                .ok_or_else(|| ParseError::at(text, key, KEYS.join(", ")))?;
+            values[slot] = Some(
+                value
+                    .parse::<Int>()
+                    .ok()
+                    .filter(|x| *x >= 0)
+                    .ok_or_else(|| ParseError::at(text, value, "a whole number"))?,
+            );
+        }
+        let end = &text[text.trim_end().len()..];
+        let [position, size, landings, clicks, lines] = values;
+        let get = |value: Option<Int>, key: &str| {
+            value.ok_or_else(|| ParseError::at(text, end, format!("{}=", key)))
+        };
+        let size = get(size, "size")?;
+        if size == 0 {
+            return Err(ParseError::at(text, text, "a size of at least 1"));
+        }
+        Ok(Checkpoint {
+            dial: Dial::with_size(size, get(position, "position")?),
+            landings: get(landings, "landings")?,
+            clicks: get(clicks, "clicks")?,
+            lines: get(lines, "lines")? as usize,
+        })
+    }
+}
+
+impl std::fmt::Display for Checkpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "position={} size={} landings={} clicks={} lines={}",
+            self.dial.read(),
+            self.dial.size(),
+            self.landings,
+            self.clicks,
+            self.lines
+        )
+    }
+}
+
+// The dial and both passwords after some number of rotations
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct State {
+    pub step: usize,
+    pub dial: Dial,
+    pub landings: Int,
+    pub clicks: Int,
+}
+
+// A record of rotations that can be stepped back and forth through. The positions
+// and both counts after every step are kept as prefix sums, so that any step can
+// be looked up at once rather than replayed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct History {
+    rotations: Vec<Int>,
+    // after each step, starting with the state before any rotation
+    positions: Vec<Int>,
+    landings: Vec<Int>,
+    clicks: Vec<Int>,
+    size: Int,
+    step: usize,
+}
+
+impl History {
+    pub fn new(start: Dial) -> Self {
+        History {
+            rotations: Vec::new(),
+            positions: vec![start.read()],
+            landings: vec![0],
+            clicks: vec![0],
+            size: start.size(),
+            step: 0,
+        }
+    }
+
+    // Record the rotations in turn, ending up after the last of them
+    pub fn from_rotations(
+        start: Dial,
+        rotations: impl IntoIterator<Item = Int>,
+    ) -> Result<Self, Error> {
+        let mut history = Self::new(start);
+        for rotation in rotations {
+            history.push(rotation)?;
+        }
+        Ok(history)
+    }
+
+    // Make a rotation from the current step, forgetting any steps that were undone.
+    // Leaves the history be if either count would no longer fit in an Int.
+    pub fn push(&mut self, rotation: Int) -> Result<State, Error> {
+        let step = self.step;
+        let mut dial = Dial::with_size(self.size, self.positions[step]);
+        let clicks = dial.checked_spin(rotation).ok_or(Error::Overflow)?;
+        let landings = self.landings[step].checked_add((dial.read() == 0) as Int);
+        let clicks = self.clicks[step].checked_add(clicks);
+        let (Some(landings), Some(clicks)) = (landings, clicks) else {
+            return Err(Error::Overflow);
+        };
+        self.rotations.truncate(step);
+        for prefix in [&mut self.positions, &mut self.landings, &mut self.clicks] {
+            prefix.truncate(step + 1);
+        }
+        self.rotations.push(rotation);
+        self.positions.push(dial.read());
+        self.landings.push(landings);
+        self.clicks.push(clicks);
+        self.step += 1;
+        Ok(self.state())
+    }
+
+    // The number of recorded rotations, including any that were undone
+    pub fn len(&self) -> usize {
+        self.rotations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rotations.is_empty()
+    }
+
+    pub fn rotations(&self) -> &[Int] {
+        &self.rotations
+    }
+
+    pub fn state(&self) -> State {
+        self.at(self.step).expect("the current step is recorded")
+    }
+
+    // The state after the first step rotations, if there were that many
+    pub fn at(&self, step: usize) -> Option<State> {
+        Some(State {
+            step,
+            dial: Dial::with_size(self.size, *self.positions.get(step)?),
+            landings: self.landings[step],
+            clicks: self.clicks[step],
+        })
+    }
+
+    // Go back one rotation, unless already at the start
+    pub fn undo(&mut self) -> Option<State> {
+        self.seek(self.step.checked_sub(1)?)
+    }
+
+    // Make the next recorded rotation again, unless there are none left
+    pub fn redo(&mut self) -> Option<State> {
+        self.seek(self.step + 1)
+    }
+
+    // Go to the state after the first step rotations, if there were that many
+    pub fn seek(&mut self, step: usize) -> Option<State> {
+        let state = self.at(step)?;
+        self.step = step;
+        Some(state)
+    }
+
+    // The first state that matches, for a test that once true stays true as the
+    // steps go on, such as the clicks reaching some count. Takes O(log n) lookups.
+    pub fn bisect(&self, test: impl Fn(&State) -> bool) -> Option<State> {
+        // the first match is in low..high, or is past the end if nothing matches
+        let (mut low, mut high) = (0, self.len() + 1);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if test(&self.at(mid)?) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        self.at(low)
+    }
+}
+
+// Like process, but describing every step in full
+pub fn trace(dial: Dial, rotations: impl IntoIterator<Item = Int>) -> impl Iterator<Item = Step> {
+    rotations.into_iter().scan(dial, |dial, rotation| {
+        let (start, size) = (dial.read(), dial.size() as usize);
+        // clicks to reach zero for the first time, which is a full turn from zero
+        let first = match (start as usize, rotation.is_negative()) {
+            (0, _) => size,
+            (p, true) => p,
+            (p, false) => size - p,
+        };
+        let clicks = rotation.unsigned_abs();
+        let zero_clicks = match clicks.checked_sub(first) {
+            Some(rest) => rest / size + 1,
+            None => 0,
+        };
+        dial.turn(rotation);
+        Some(Step {
+            rotation,
+            start,
+            end: dial.read(),
+            zero_clicks,
+            first_zero_click: first,
+            spacing: size,
+        })
+    })
+}
+
+// The face of the dial as a ruler with the pointer under the current position. A
+// dial of more than 100 positions is scaled down to 100 columns, in 128 bits so
+// that no dial is too large to scale.
+pub fn render(dial: &Dial) -> String {
+    let size = dial.size();
+    let columns = size.min(100);
+    let scale = |x: Int, from: Int, to: Int| (x as i128 * to as i128 / from as i128) as Int;
+    let at = |column: Int| scale(column, columns, size);
+    let mut labels = String::new();
+    for column in (0..columns).step_by(10) {
+        let label = format!("{:<10}", at(column));
+        labels += &label[..label.len().min(10)];
+    }
+    labels.truncate(columns as usize);
+    let ruler: String = (0..columns)
+        .map(|column| match column % 10 {
+            0 => '|',
+            5 => '+',
+            _ => '-',
+        })
+        .collect();
+    let pointer = scale(dial.read(), size, columns);
+    format!(
+        "{}\n{}\n{}^ {}",
+        labels.trim_end(),
+        ruler,
+        " ".repeat(pointer as usize),
+        dial.read()
+    )
+}
+
+// A rotation as parse_rotation reads it, such as L68 or R48
+pub fn format_rotation(rotation: Int) -> String {
+    if rotation.is_negative() {
+        format!("L{}", rotation.unsigned_abs())
+    } else {
+        format!("R{}", rotation)
+    }
+}
+
+// The rotations that stop at each of the stops in turn with the fewest clicks in
+// total, clicking onto zero at most max_zero_clicks times when given. No rotation
+// is a full turn or more, since that only adds clicks. None if the cap is too low.
+pub fn plan(dial: Dial, stops: &[Int], max_zero_clicks: Option<usize>) -> Option<Vec<Int>> {
+    let size = dial.size();
+    // the ways right and left to each stop from the one before, with their zero clicks
+    let moves: Vec<[(Int, usize); 2]> = stops
+        .iter()
+        .scan(dial, |dial, stop| {
+            let right = (stop - dial.read()).rem_euclid(size);
+            let left = if right == 0 { 0 } else { right - size };
+            let moves = [right, left].map(|r| (r, dial.count_clicks_at(0, r) as usize));
+            *dial = Dial::with_size(size, *stop);
+            Some(moves)
+        })
+        .collect();
+    let Some(cap) = max_zero_clicks else {
+        let shortest = |moves: &[(Int, usize); 2]| {
+            let (r, _) = moves.iter().min_by_key(|(r, z)| (r.abs(), *z)).unwrap();
+            *r
+        };
+        return Some(moves.iter().map(shortest).collect());
+    };
+    // the fewest clicks so far for each number of zero clicks so far, and for each
+    // stop which way reached each number of zero clicks
+    let cap = cap.min(moves.len());
+    let mut clicks = vec![None; cap + 1];
+    clicks[0] = Some(0);
+    let mut ways = Vec::with_capacity(moves.len());
+    for options in &moves {
+        let mut next: Vec<Option<Int>> = vec![None; cap + 1];
+        let mut way = vec![0; cap + 1];
+        for (z, c) in clicks.iter().enumerate() {
+            let Some(c) = c else { continue };
+            for (i, (r, dz)) in options.iter().enumerate() {
+                let (z, c) = (z + dz, c + r.abs());
+                if z <= cap && next[z].is_none_or(|best| c < best) {
+                    next[z] = Some(c);
+                    way[z] = i;
+                }
+            }
+        }
+        clicks = next;
+        ways.push(way);
+    }
+    let (mut z, _) = clicks
+        .iter()
+        .enumerate()
+        .filter_map(|(z, c)| c.map(|c| (z, c)))
+        .min_by_key(|(_, c)| *c)?;
+    let mut rotations = vec![0; moves.len()];
+    for (k, (options, way)) in zip(&moves, &ways).enumerate().rev() {
+        let (r, dz) = options[way[z]];
+        rotations[k] = r;
+        z -= dz;
+    }
+    Some(rotations)
+}
+
+// Random rotations of up to 999 clicks, one per line
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}{}\n", rng.choose(&['L', 'R']), rng.range(1..=999)))
+        .collect()
+}
+
+// The runs of rotations in a log and the dial they start from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Log<I = Int> {
+    pub dial: Dial<I>,
+    pub runs: Vec<(usize, I)>,
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Log;
+    type Answer = Int;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        Self::parse_with(text, &Settings::default())
+    }
+
+    fn parse_with(text: &str, settings: &Settings) -> Result<Self::Input, Error> {
+        Ok(Log {
+            dial: settings.dial,
+            runs: parse_log(text)?,
+        })
+    }
+
+    // Count the rotations that leave the dial at zero
+    fn part1(log: &Self::Input) -> Result<Self::Answer, Error> {
+        passwords(log.dial, log.runs.iter().copied()).map(|(landings, _)| landings)
+    }
+
+    // Count every click through zero
+    fn part2(log: &Self::Input) -> Result<Self::Answer, Error> {
+        passwords(log.dial, log.runs.iter().copied()).map(|(_, clicks)| clicks)
+    }
+}
+
+// The same solution counting in 128 bits, which the runner uses with the wide feature
+pub struct WideDay01;
+
+impl Solution for WideDay01 {
+    type Input = Log<i128>;
+    type Answer = i128;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        Self::parse_with(text, &Settings::default())
+    }
+
+    fn parse_with(text: &str, settings: &Settings) -> Result<Self::Input, Error> {
+        let dial = settings.dial;
+        Ok(Log {
+            dial: WideDial::with_size(dial.size() as i128, dial.read() as i128),
+            runs: parse_log(text)?,
+        })
+    }
+
+    fn part1(log: &Self::Input) -> Result<Self::Answer, Error> {
+        passwords(log.dial, log.runs.iter().copied()).map(|(landings, _)| landings)
+    }
+
+    fn part2(log: &Self::Input) -> Result<Self::Answer, Error> {
+        passwords(log.dial, log.runs.iter().copied()).map(|(_, clicks)| clicks)
+    }
+}
+
+// Both passwords, the landings on zero and the clicks onto zero, from the dial
+// through runs of a count and a rotation, checking that the counts fit
+pub fn passwords<I: DialInt>(
+    mut dial: Dial<I>,
+    runs: impl IntoIterator<Item = (usize, I)>,
+) -> Result<(I, I), Error> {
+    let zero = I::from_isize(0);
+    runs.into_iter()
+        .try_fold((zero, zero), |(landings, clicks), (count, rotation)| {
+            let (landed, clicked) = dial.checked_spin_run(count, rotation)?;
+            Some((landings.checked_add(landed)?, clicks.checked_add(clicked)?))
+        })
+        .ok_or(Error::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    const ROTATIONS: [Int; 10] = [-68, -30, 48, -5, 60, -55, -1, -99, 14, -82];
+
+    #[test]
+    fn test_parse_sample() {
+        let rotations: Vec<_> = CONTENT
+            .lines()
+            .map(|line| parse_rotation(line).unwrap())
+            .collect();
+        assert_eq!(rotations, ROTATIONS);
+    }
+
+    #[test]
+    fn test_process_sample() {
+        let xs: Vec<_> = process(Dial::default(), ROTATIONS).collect();
+        assert_eq!(
+            xs,
+            [
+                (82, 1),
+                (52, 0),
+                (0, 1),
+                (95, 0),
+                (55, 1),
+                (0, 1),
+                (99, 0),
+                (0, 1),
+                (14, 0),
+                (32, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clicks_with_cycles() {
+        assert_eq!(Dial::new(0).spin(300), 3);
+        assert_eq!(Dial::new(10).spin(-110), 2);
+    }
+
+    #[test]
+    fn test_large_rotations() {
+        let mut dial = Dial::new(50);
+        let clicks = Int::MAX / 100 + (Int::MAX % 100 + 50 >= 100) as Int;
+        assert_eq!(dial.spin(Int::MAX), clicks);
+        assert_eq!(dial.read(), (Int::MAX % 100 + 50) % 100);
+        let mut dial = Dial::new(50);
+        let clicks = -(Int::MIN / 100) + (-(Int::MIN % 100) >= 50) as Int;
+        assert_eq!(dial.spin(Int::MIN), clicks);
+        assert_eq!(Dial::with_size(1, 0).checked_spin(Int::MIN), None);
+        assert_eq!(Dial::with_size(1, 0).checked_spin(Int::MAX), Some(Int::MAX));
+        let beyond_isize = "R9223372036854775808";
+        assert!(parse_rotation(beyond_isize).is_err());
+        let rotations = WideDay01::parse(beyond_isize).unwrap();
+        assert_eq!(WideDay01::part2(&rotations).unwrap(), 92233720368547758);
+        let mut dial = WideDial::default();
+        assert_eq!(dial.spin(i128::MAX), i128::MAX / 100);
+        let e = parse_rotation(&format!("R{}0", Int::MAX)).unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (2, "a number"));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let text = format!("R{}\nR1\n", Int::MAX);
+        let mut checkpoint = Checkpoint::new(Dial::with_size(1, 0));
+        let result = checkpoint.feed(text.as_bytes(), |e| panic!("{}", e));
+        assert!(matches!(result, Err(Error::Overflow)));
+        assert_eq!(checkpoint.clicks, Int::MAX);
+        // a line that overflows part way through is not applied at all
+        let mut checkpoint = Checkpoint::new(Dial::with_size(1, 0));
+        let before = checkpoint;
+        let text = format!("R5 R{}\n", Int::MAX);
+        let result = checkpoint.feed(text.as_bytes(), |e| panic!("{}", e));
+        assert!(matches!(result, Err(Error::Overflow)));
+        assert_eq!(checkpoint, before);
+        let mut dial = Dial::new(50);
+        assert_eq!(dial.checked_spin_run(usize::MAX, 2), None);
+        assert_eq!(dial, Dial::new(50));
+        let input = Day01::parse(&format!("L{}\nR{}", Int::MAX, Int::MAX)).unwrap();
+        assert!(Day01::part2(&input).is_ok());
+        let rotations = [Int::MAX, Int::MAX];
+        let counted = count_visits(Dial::with_size(1, 0), rotations, &[0]);
+        assert!(matches!(counted, Err(Error::Overflow)));
+        assert!(matches!(
+            passwords_by_start(1, rotations),
+            Err(Error::Overflow)
+        ));
+        let mut lock = Lock::odometer(2, 1);
+        assert!(lock.spin(0, Int::MAX).is_ok());
+        let before = lock.clone();
+        assert!(matches!(lock.spin(0, Int::MIN), Err(Error::Overflow)));
+        assert_eq!(lock, before);
+        assert!(matches!(
+            lock.run([(0, Int::MAX), (0, 1)]),
+            Err(Error::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_dial_size() {
+        let mut dial: Dial = Dial::with_size(60, -5);
+        assert_eq!((dial.read(), dial.size()), (55, 60));
+        assert_eq!(dial.spin(130), 3);
+        assert_eq!(dial.read(), 5);
+        assert_eq!(dial.spin(-5), 1);
+        let xs: Vec<_> = process(Dial::with_size(256, 0), [-1, 257, -256]).collect();
+        assert_eq!(xs, [(255, 0), (0, 2), (0, 1)]);
+    }
+
+    #[test]
+    fn test_count_visits() {
+        let visits: Vec<_> = count_visits(Dial::default(), ROTATIONS, &[0, 152, -1])
+            .unwrap()
+            .into_iter()
+            .map(|(target, v)| (target, v.landings, v.clicks))
+            .collect();
+        assert_eq!(visits, [(0, 3, 6), (52, 1, 5), (99, 1, 6)]);
+        assert_eq!(Dial::new(10).count_clicks_at(10, 200), 2);
+        assert_eq!(Dial::new(10).count_clicks_at(5, -6), 1);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let visits = histogram(Dial::with_size(60, 0), ROTATIONS).unwrap();
+        assert_eq!(visits.len(), 60);
+        let landings: Int = visits.iter().map(|v| v.landings).sum();
+        let clicks: Int = visits.iter().map(|v| v.clicks).sum();
+        assert_eq!(landings, ROTATIONS.len() as Int);
+        assert_eq!(clicks, ROTATIONS.iter().map(|r| r.abs()).sum());
+    }
+
+    #[test]
+    fn test_parse_lock_rotation() {
+        assert_eq!(parse_lock_rotation("2:L30").unwrap(), (1, -30));
+        assert_eq!(parse_lock_rotation("R5").unwrap(), (0, 5));
+        let e = parse_lock_rotation("2L30").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (2, "':'"));
+        let e = parse_lock_rotation("0:L30").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (1, "a dial number"));
+        let lock = Lock::odometer(2, 10);
+        let e = lock.parse_rotations("1:R1\n3:R1\n").unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (2, "a dial from 1 to 2"));
+    }
+
+    #[test]
+    fn test_odometer() {
+        let mut lock = Lock::odometer(3, 10);
+        assert_eq!(lock.spin(0, 25).unwrap(), [2, 0, 0]);
+        assert_eq!(lock.spin(0, 978).unwrap(), [98, 10, 1]);
+        let positions: Vec<_> = lock.dials().iter().map(Dial::read).collect();
+        assert_eq!(positions, [3, 0, 0]);
+        assert_eq!(lock.spin(0, -4).unwrap(), [1, 0, 0]);
+        let positions: Vec<_> = lock.dials().iter().map(Dial::read).collect();
+        assert_eq!(positions, [9, 9, 9]);
+    }
+
+    #[test]
+    fn test_lock() {
+        let dials = vec![Dial::default(), Dial::new(0), Dial::with_size(60, 30)];
+        let couplings = vec![
+            Coupling {
+                from: 0,
+                notch: 50,
+                to: 2,
+            },
+            Coupling {
+                from: 1,
+                notch: 0,
+                to: 2,
+            },
+        ];
+        let mut lock = Lock::new(dials, couplings);
+        let rotations = lock.parse_rotations(CONTENT).unwrap();
+        let mut single = lock.clone();
+        // the first dial turns on its own just as in the puzzle
+        assert_eq!(lock.run(rotations).unwrap()[0], 6);
+        assert_eq!(single.run([(1, 250), (0, 100)]).unwrap(), [1, 2, 0]);
+        let positions: Vec<_> = single.dials().iter().map(Dial::read).collect();
+        assert_eq!(positions, [50, 50, 33]);
+    }
+
+    #[test]
+    fn test_plan() {
+        assert_eq!(plan(Dial::default(), &[10, 90], None).unwrap(), [-40, -20]);
+        assert_eq!(
+            plan(Dial::default(), &[10, 90], Some(0)).unwrap(),
+            [-40, 80]
+        );
+        assert_eq!(plan(Dial::default(), &[50, 0], Some(1)).unwrap(), [0, 50]);
+        assert_eq!(plan(Dial::default(), &[0], Some(0)), None);
+        let stops = [82, 52, 0, 95, 55, 0, 99, 0, 14, 32];
+        let rotations = plan(Dial::default(), &stops, Some(3)).unwrap();
+        let text: String = rotations
+            .iter()
+            .map(|r| format_rotation(*r) + "\n")
+            .collect();
+        let (positions, clicks): (Vec<_>, Vec<_>) =
+            process(Dial::default(), expand(&Day01::parse(&text).unwrap().runs)).unzip();
+        assert_eq!(positions, stops);
+        assert_eq!(clicks.iter().sum::<Int>(), 3);
+    }
+
+    #[test]
+    fn test_passwords_by_start() {
+        let passwords = passwords_by_start(Dial::SIZE, ROTATIONS).unwrap();
+        assert_eq!(passwords.len(), 100);
+        assert_eq!(passwords[50], (3, 6));
+        for size in [1, 7, 60, 100, 256] {
+            let passwords = passwords_by_start(size, ROTATIONS).unwrap();
+            for (start, passwords) in (0..).zip(passwords) {
+                let (landings, clicks) = process(Dial::with_size(size, start), ROTATIONS).fold(
+                    (0, 0),
+                    |(landings, clicks), (position, c)| {
+                        (landings + (position == 0) as Int, clicks + c)
+                    },
+                );
+                assert_eq!(passwords, (landings, clicks), "start {} of {}", start, size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let mut checkpoint = Checkpoint::new(Dial::default());
+        let mut skipped = Vec::new();
+        let (first, rest) = CONTENT.split_at(CONTENT.find("L55").unwrap());
+        checkpoint
+            .feed(first.as_bytes(), |e| skipped.push(e))
+            .unwrap();
+        let saved = checkpoint.to_string();
+        assert_eq!(saved, "position=55 size=100 landings=1 clicks=3 lines=5");
+        let mut resumed = Checkpoint::parse(&(saved + "\n")).unwrap();
+        assert_eq!(resumed, checkpoint);
+        let rest = rest.replace("L99", "L99x\nQ\nL99");
+        resumed.feed(rest.as_bytes(), |e| skipped.push(e)).unwrap();
+        assert_eq!((resumed.landings, resumed.clicks), (3, 6));
+        let lines: Vec<_> = skipped.iter().map(|e| e.line).collect();
+        assert_eq!(lines, [8, 9]);
+        assert_eq!(resumed.lines, 12);
+    }
+
+    #[test]
+    fn test_checkpoint_parse_error() {
+        let e = Checkpoint::parse("position=1 size=100 landings=0 clicks=-1 lines=0").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (39, "a whole number"));
+        let e = Checkpoint::parse("position=1 size=100 landings=0 clicks=1").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (40, "lines="));
+        let e = Checkpoint::parse("position=1 turns=2").unwrap_err();
+        assert_eq!(e.column, 12);
+        assert!(Checkpoint::parse("position=1 size=0 landings=0 clicks=0 lines=0").is_err());
+    }
+
+    #[test]
+    fn test_history() {
+        let mut history = History::from_rotations(Dial::default(), ROTATIONS).unwrap();
+        assert_eq!(history.len(), 10);
+        let state = history.state();
+        assert_eq!((state.step, state.dial.read()), (10, 32));
+        assert_eq!((state.landings, state.clicks), (3, 6));
+        assert_eq!(history.redo(), None);
+        let state = history.undo().unwrap();
+        assert_eq!((state.step, state.dial.read(), state.clicks), (9, 14, 5));
+        let state = history.seek(3).unwrap();
+        assert_eq!((state.dial.read(), state.landings, state.clicks), (0, 1, 2));
+        assert_eq!(history.redo().unwrap().dial.read(), 95);
+        assert_eq!(history.seek(11), None);
+        assert_eq!(history.state().step, 4);
+        // a new rotation replaces the steps that were undone
+        history.seek(2);
+        let state = history.push(-52).unwrap();
+        assert_eq!((state.step, state.dial.read(), state.clicks), (3, 0, 2));
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.rotations(), [-68, -30, -52]);
+        assert_eq!(
+            history.seek(0).unwrap(),
+            History::new(Dial::default()).state()
+        );
+        assert_eq!(history.undo(), None);
+        // a rotation whose clicks do not fit is refused, keeping the steps undone
+        let mut history = History::from_rotations(Dial::with_size(1, 0), [5, 3]).unwrap();
+        history.undo();
+        let before = history.clone();
+        assert!(matches!(history.push(Int::MIN), Err(Error::Overflow)));
+        assert_eq!(history, before);
+        let history = History::from_rotations(Dial::with_size(1, 0), [Int::MIN]);
+        assert!(matches!(history, Err(Error::Overflow)));
+        let history = History::from_rotations(Dial::with_size(1, 0), [Int::MAX, 1]);
+        assert!(matches!(history, Err(Error::Overflow)));
+    }
+
+    #[test]
+    fn test_bisect() {
+        let history = History::from_rotations(Dial::default(), ROTATIONS).unwrap();
+        assert_eq!(history.bisect(|state| state.clicks >= 4).unwrap().step, 6);
+        assert_eq!(history.bisect(|state| state.landings >= 3).unwrap().step, 8);
+        assert_eq!(history.bisect(|_| true).unwrap().step, 0);
+        assert_eq!(history.bisect(|state| state.clicks > 6), None);
+    }
+
+    #[test]
+    fn test_trace() {
+        let steps: Vec<_> = trace(Dial::default(), ROTATIONS).collect();
+        assert_eq!(steps[0].to_string(), "L68: 50 -> 82, zero clicks 1 (at 50)");
+        assert_eq!(steps[1].to_string(), "L30: 82 -> 52, zero clicks 0");
+        let clicks = steps.iter().map(|step| step.zero_clicks as Int);
+        assert!(clicks.eq(process(Dial::default(), ROTATIONS).map(|(_, clicks)| clicks)));
+        // leaving zero backwards does not count until the dial comes round again
+        let steps: Vec<_> = trace(Dial::new(0), [-1, -300, 250]).collect();
+        let numbers = |step: &Step| step.zero_click_numbers().collect::<Vec<_>>();
+        assert_eq!(numbers(&steps[0]), []);
+        assert_eq!(numbers(&steps[1]), [99, 199, 299]);
+        assert_eq!(numbers(&steps[2]), [1, 101, 201]);
+        assert_eq!(steps[2].end, 49);
+        // long rotations list only the first few clicks
+        let step = trace(Dial::default(), [1_000_000_000_000]).next().unwrap();
+        let text = "R1000000000000: 50 -> 50, zero clicks 10000000000 \
+            (at 50, 150, 250, 350, 450 and 9999999995 more)";
+        assert_eq!(step.to_string(), text);
+        let step = trace(Dial::with_size(2, 1), [Int::MIN]).next().unwrap();
+        assert_eq!((step.zero_clicks, step.end), (1 << (Int::BITS - 2), 1));
+        let text = format!("L{}: 1 -> 1", Int::MIN.unsigned_abs());
+        assert!(step.to_string().starts_with(&text));
+    }
+
+    #[test]
+    fn test_render() {
+        let face = render(&Dial::with_size(20, 7));
+        assert_eq!(face, "0         10\n|----+----|----+----\n       ^ 7");
+        let face = render(&Dial::with_size(1000, 995));
+        let lines: Vec<_> = face.lines().collect();
+        assert_eq!(lines[1].len(), 100);
+        assert!(lines[0].starts_with("0         100       200"));
+        assert_eq!(lines[2], format!("{}^ 995", " ".repeat(99)));
+        // the largest dials scale down without overflowing
+        let face = render(&Dial::with_size(Int::MAX, Int::MAX - 1));
+        let lines: Vec<_> = face.lines().collect();
+        assert_eq!(lines[1].len(), 100);
+        assert_eq!(lines[2], format!("{}^ {}", " ".repeat(99), Int::MAX - 1));
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day01::parse(CONTENT).unwrap();
+        assert_eq!(Day01::part1(&input).unwrap(), 3);
+        assert_eq!(Day01::part2(&input).unwrap(), 6);
+        let dial = Dial::with_size(60, 0);
+        let landings = process(dial, ROTATIONS).filter(|(p, _)| *p == 0).count();
+        let clicks = process(dial, ROTATIONS).map(|(_, c)| c).sum();
+        let runs = ROTATIONS.map(|rotation| (1, rotation));
+        assert_eq!(passwords(dial, runs).unwrap(), (landings as Int, clicks));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_rotation("L6x").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 3));
+        assert_eq!(e.expected, "a digit");
+        let e = parse_rotation("X10").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (1, "L or R"));
+        let Err(Error::Parse(e)) = Day01::parse("L68\nR\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (2, 2));
+        assert_eq!(e.expected, "a number");
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        assert_eq!(parse_moves::<Int>("L68").unwrap(), [(1, -68)]);
+        assert_eq!(
+            parse_moves::<Int>("  3xL25 R4,L1 , 2xR0 # spins").unwrap(),
+            [(3, -25), (1, 4), (1, -1), (2, 0)]
+        );
+        assert_eq!(parse_moves::<Int>("").unwrap(), []);
+        assert_eq!(parse_moves::<Int>(" # nothing").unwrap(), []);
+        let text = "# log\n2xL68\n\nL30 R48 # last";
+        let runs = parse_log::<Int>(text).unwrap();
+        assert_eq!(runs, [(2, -68), (1, -30), (1, 48)]);
+        assert!(expand(&runs).eq([-68, -68, -30, 48]));
+        // a long run is kept as it is rather than spelled out
+        let log = Day01::parse("1000000000000xR1\nL1").unwrap();
+        assert_eq!(log.runs, [(1_000_000_000_000, 1), (1, -1)]);
+        assert_eq!(Day01::part1(&log).unwrap(), 10_000_000_000);
+        assert_eq!(Day01::part2(&log).unwrap(), 10_000_000_000);
+        // and neither needs the total rotation to fit, only the answers
+        let log = Day01::parse("5000000000000000000xR2").unwrap();
+        assert_eq!(Day01::part1(&log).unwrap(), 100_000_000_000_000_000);
+        assert_eq!(Day01::part2(&log).unwrap(), 100_000_000_000_000_000);
+        let log = Day01::parse("5000000000000000000xL3").unwrap();
+        assert_eq!(Day01::part1(&log).unwrap(), 50_000_000_000_000_000);
+        assert_eq!(Day01::part2(&log).unwrap(), 150_000_000_000_000_000);
+        let log = Day01::parse(&format!("1000xR{}", Int::MAX)).unwrap();
+        assert!(matches!(Day01::part2(&log), Err(Error::Overflow)));
+        // the settings give the dial to start from
+        let settings = Settings {
+            dial: Dial::with_size(60, 0),
+            ..Settings::default()
+        };
+        let log = Day01::parse_with("R60\nL120", &settings).unwrap();
+        assert_eq!(Day01::part1(&log).unwrap(), 2);
+        assert_eq!(Day01::part2(&log).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_parse_moves_error() {
+        let error = |line| {
+            let e = parse_moves::<Int>(line).unwrap_err();
+            (e.column, e.expected)
+        };
+        assert_eq!(error("3L25"), (2, "'x'".to_owned()));
+        assert_eq!(error("0xL25"), (1, "a count of at least 1".to_owned()));
+        assert_eq!(error("L25,"), (5, "L or R".to_owned()));
+        assert_eq!(error("L25;R3"), (4, "',', a space or '#'".to_owned()));
+        assert_eq!(error("Q5"), (1, "L or R".to_owned()));
+        assert_eq!(error("R5 L"), (5, "a number".to_owned()));
+        let e = parse_log::<Int>("L1\n\nR2 X3\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 4));
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // twenty rotations of 1 to 999 clicks
+        let rotations: Vec<_> = expand(&parse_log::<Int>(&text).unwrap()).collect();
+        assert_eq!(rotations.len(), 20);
+        assert!(rotations.iter().all(|r| (1..=999).contains(&r.abs())));
+    }
+}
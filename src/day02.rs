@@ -0,0 +1,932 @@
+// Advent of Code 2025 Day 2
+// A. Drew
+
+use crate::parse::{parse_all, IResult};
+use crate::rng::Rng;
+use crate::runner::Part;
+use crate::{Error, ParseError, Settings, Solution};
+
+pub fn parse_range(input: &str) -> Result<[u64; 2], ParseError> {
+    parse_range_radix(input, 10)
+}
+
+// A range with ids written in the radix, which is from 2 to 36. Binary, octal and
+// hexadecimal ids may start with 0b, 0o or 0x.
+pub fn parse_range_radix(input: &str, radix: u32) -> Result<[u64; 2], ParseError> {
+    check_radix(input, radix)?;
+    parse_all(input, range(radix))
+}
+
+pub fn parse_ranges_radix(text: &str, radix: u32) -> Result<Vec<[u64; 2]>, ParseError> {
+    use nom::{character::complete::char, multi::separated_list1};
+    check_radix(text, radix)?;
+    parse_all(text, separated_list1(char(','), range(radix)))
+}
+
+// A radix outside 2 to 36 is an error at the start of the text, as no id can be
+// read in it
+fn check_radix(text: &str, radix: u32) -> Result<(), ParseError> {
+    match radix {
+        2..=36 => Ok(()),
+        _ => Err(ParseError::at(text, text, "a radix from 2 to 36")),
+    }
+}
+
+// A range of ids in the radix, which the callers have checked is from 2 to 36
+fn range<'a>(radix: u32) -> impl FnMut(&'a str) -> IResult<'a, [u64; 2]> {
+    use nom::{
+        bytes::complete::{tag, take_while1},
+        character::complete::{char, multispace0},
+        combinator::{cut, opt},
+        error::context,
+        sequence::{delimited, preceded, separated_pair},
+        Parser,
+    };
+    let prefix = prefix(radix).unwrap_or_default();
+    move |input| {
+        let id = || {
+            let digits = take_while1(move |c: char| c.is_digit(radix));
+            let id = preceded(opt(tag(prefix)), digits);
+            context("an id", id.map_res(move |x| u64::from_str_radix(x, radix)))
+        };
+        // once a range has started it must be complete
+        let range = separated_pair(id(), cut(char('-')), cut(id()));
+        delimited(multispace0, range, multispace0)
+            .map(|(a, b)| [a, b])
+            .parse(input)
+    }
+}
+
+fn prefix(radix: u32) -> Option<&'static str> {
+    match radix {
+        2 => Some("0b"),
+        8 => Some("0o"),
+        16 => Some("0x"),
+        _ => None,
+    }
+}
+
+// What to do with a range written backwards, such as 22-11
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reversed {
+    // read it the right way round, as 11-22
+    Swap,
+    // leave it out
+    Skip,
+}
+
+// The ranges the right way round, with the reversed ones swapped or left out, and
+// the indices of the ranges that were reversed
+pub fn orient(ranges: &[[u64; 2]], reversed: Reversed) -> (Vec<[u64; 2]>, Vec<usize>) {
+    let backwards: Vec<_> = (0..ranges.len())
+        .filter(|i| ranges[*i][0] > ranges[*i][1])
+        .collect();
+    let oriented = ranges
+        .iter()
+        .filter_map(|&[a, b]| match (a <= b, reversed) {
+            (true, _) => Some([a, b]),
+            (false, Reversed::Swap) => Some([b, a]),
+            (false, Reversed::Skip) => None,
+        })
+        .collect();
+    (oriented, backwards)
+}
+
+// The ranges, which must be the right way round, sorted with those that overlap or
+// touch merged so that each id is in at most one
+pub fn merge(ranges: &[[u64; 2]]) -> Vec<[u64; 2]> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort();
+    let mut merged: Vec<[u64; 2]> = Vec::with_capacity(sorted.len());
+    for [a, b] in sorted {
+        match merged.last_mut() {
+            Some(last) if a <= last[1].saturating_add(1) => last[1] = last[1].max(b),
+            _ => merged.push([a, b]),
+        }
+    }
+    merged
+}
+
+// The digits of x in the radix, most significant first, as in 0x1f -> [1, 15]
+pub fn digits(mut x: u128, radix: u32) -> Vec<u8> {
+    let mut digits = Vec::new();
+    loop {
+        digits.push((x % radix as u128) as u8);
+        x /= radix as u128;
+        if x == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+// Write x in the radix with lower case letters, without any prefix
+pub fn format_radix(x: u128, radix: u32) -> String {
+    digits(x, radix)
+        .into_iter()
+        .map(|d| char::from_digit(d as u32, radix).expect("digit is below the radix"))
+        .collect()
+}
+
+pub fn repeats_every(sequence: &[u8], n: usize) -> bool {
+    let mut iter = sequence.chunks(n);
+    iter.next().is_some_and(|head| iter.all(|x| head == x))
+}
+
+// Which ids are invalid, by how many times their digits repeat a block
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Policy {
+    // a block repeated exactly twice, as in part 1
+    Twice,
+    // a block repeated two or more times, as in part 2
+    Any,
+}
+
+impl Policy {
+    fn allows(self, times: u32) -> bool {
+        match self {
+            Policy::Twice => times == 2,
+            Policy::Any => times >= 2,
+        }
+    }
+
+    // The block lengths to count the ids of a number of digits with, each with a
+    // sign. An id can repeat more than one block length, as 222222 repeats 222 and
+    // 22, so for Any this is inclusion-exclusion over the longest blocks, which
+    // are digits / p for each prime p dividing digits: every repeating id repeats
+    // one of those, and an id that repeats several repeats their common divisor.
+    fn terms(self, digits: u32) -> Vec<(u32, i128)> {
+        match self {
+            Policy::Twice if digits.is_multiple_of(2) => vec![(digits / 2, 1)],
+            Policy::Twice => Vec::new(),
+            Policy::Any => {
+                let primes: Vec<_> = (2..=digits)
+                    .filter(|p| digits.is_multiple_of(*p) && (2..*p).all(|q| !p.is_multiple_of(q)))
+                    .collect();
+                (1..1u32 << primes.len())
+                    .map(|subset| {
+                        let chosen = primes
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| subset >> i & 1 == 1);
+                        let product: u32 = chosen.map(|(_, p)| p).product();
+                        let sign = if subset.count_ones() % 2 == 1 { 1 } else { -1 };
+                        (digits / product, sign)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+// A way of telling invalid ids from their digits. Rules without a quicker way find
+// and sum the invalid ids by testing every id in the range.
+pub trait IdRule {
+    // Whether the id with these digits, most significant first, is invalid
+    fn is_invalid(&self, digits: &[u8]) -> bool;
+
+    fn find(&self, range: [u64; 2], radix: u32) -> Vec<u64> {
+        let [a, b] = range;
+        (a..=b)
+            .filter(|id| self.is_invalid(&digits(*id as u128, radix)))
+            .collect()
+    }
+
+    fn sum(&self, range: [u64; 2], radix: u32) -> u128 {
+        self.find(range, radix).into_iter().map(u128::from).sum()
+    }
+}
+
+impl IdRule for Policy {
+    fn is_invalid(&self, digits: &[u8]) -> bool {
+        let n = digits.len();
+        (1..n).any(|block| {
+            n.is_multiple_of(block)
+                && self.allows((n / block) as u32)
+                && repeats_every(digits, block)
+        })
+    }
+
+    fn find(&self, range: [u64; 2], radix: u32) -> Vec<u64> {
+        find_invalid_radix(range, *self, radix)
+    }
+
+    fn sum(&self, range: [u64; 2], radix: u32) -> u128 {
+        sum_invalid_radix(range, *self, radix)
+    }
+}
+
+// A block repeated exactly this many times
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Times(pub usize);
+
+impl Times {
+    // The multiplier and the first and last blocks for each number of digits in the
+    // range that this many blocks fill. There is one block length for each, so the
+    // ids from them never repeat.
+    fn blocks(self, range: [u64; 2], radix: u32) -> impl Iterator<Item = (u128, u128, u128)> {
+        let [a, b] = range.map(u128::from);
+        (digit_count(a, radix)..=digit_count(b, radix))
+            .filter(move |digits| self.0 > 1 && (*digits as usize).is_multiple_of(self.0))
+            .map(move |digits| {
+                let block = (digits as usize / self.0) as u32;
+                blocks_within(a, b, radix, digits, block)
+            })
+    }
+}
+
+impl IdRule for Times {
+    fn is_invalid(&self, digits: &[u8]) -> bool {
+        let n = digits.len();
+        self.0 > 1 && n.is_multiple_of(self.0) && repeats_every(digits, n / self.0)
+    }
+
+    fn find(&self, range: [u64; 2], radix: u32) -> Vec<u64> {
+        self.blocks(range, radix)
+            .flat_map(|(multiplier, first, last)| {
+                (first..=last).map(move |x| (x * multiplier) as u64)
+            })
+            .collect()
+    }
+
+    fn sum(&self, range: [u64; 2], radix: u32) -> u128 {
+        self.blocks(range, radix)
+            .filter(|(_, first, last)| first <= last)
+            .map(|(multiplier, first, last)| multiplier * (first + last) * (last - first + 1) / 2)
+            .sum()
+    }
+}
+
+// The same digits read backwards
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Palindrome;
+
+impl IdRule for Palindrome {
+    fn is_invalid(&self, digits: &[u8]) -> bool {
+        digits.iter().eq(digits.iter().rev())
+    }
+}
+
+// A block of at least this many digits repeated two or more times
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Periodic(pub usize);
+
+impl IdRule for Periodic {
+    fn is_invalid(&self, digits: &[u8]) -> bool {
+        let n = digits.len();
+        (self.0.max(1)..n).any(|block| n.is_multiple_of(block) && repeats_every(digits, block))
+    }
+}
+
+pub struct Not(pub Box<dyn IdRule>);
+
+impl IdRule for Not {
+    fn is_invalid(&self, digits: &[u8]) -> bool {
+        !self.0.is_invalid(digits)
+    }
+}
+
+pub struct And(pub Vec<Box<dyn IdRule>>);
+
+impl IdRule for And {
+    fn is_invalid(&self, digits: &[u8]) -> bool {
+        self.0.iter().all(|rule| rule.is_invalid(digits))
+    }
+}
+
+pub struct Or(pub Vec<Box<dyn IdRule>>);
+
+impl IdRule for Or {
+    fn is_invalid(&self, digits: &[u8]) -> bool {
+        self.0.iter().any(|rule| rule.is_invalid(digits))
+    }
+}
+
+// A rule written as an expression such as `periodic(2) & !palindrome | times(3)`.
+// The rules are twice, any, times(k), palindrome and periodic(n), which combine
+// with ! for not, & for and, | for or, and brackets, binding in that order.
+pub fn parse_rule(text: &str) -> Result<Box<dyn IdRule>, ParseError> {
+    use nom::{character::complete::multispace0, sequence::delimited};
+    parse_all(text, delimited(multispace0, rule, multispace0))
+}
+
+fn rule(input: &str) -> IResult<'_, Box<dyn IdRule>> {
+    use nom::{combinator::cut, multi::separated_list1, Parser};
+    separated_list1(operator('|'), cut(conjunction))
+        .map(|mut rules| match rules.len() {
+            1 => rules.remove(0),
+            _ => Box::new(Or(rules)),
+        })
+        .parse(input)
+}
+
+fn conjunction(input: &str) -> IResult<'_, Box<dyn IdRule>> {
+    use nom::{combinator::cut, multi::separated_list1, Parser};
+    separated_list1(operator('&'), cut(factor))
+        .map(|mut rules| match rules.len() {
+            1 => rules.remove(0),
+            _ => Box::new(And(rules)),
+        })
+        .parse(input)
+}
+
+fn factor(input: &str) -> IResult<'_, Box<dyn IdRule>> {
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{char, digit1, multispace0},
+        combinator::{cut, verify},
+        error::context,
+        sequence::{delimited, preceded},
+        Parser,
+    };
+    fn boxed(rule: impl IdRule + 'static) -> Box<dyn IdRule> {
+        Box::new(rule)
+    }
+    let argument = |least: usize, expected| {
+        let n = verify(digit1.map_res(str::parse::<usize>), move |n| *n >= least);
+        let n = delimited(multispace0, context(expected, n), multispace0);
+        cut(delimited(
+            char('('),
+            n,
+            context("a closing bracket", char(')')),
+        ))
+    };
+    let factor = alt((
+        tag("twice").map(|_| boxed(Policy::Twice)),
+        tag("any").map(|_| boxed(Policy::Any)),
+        tag("palindrome").map(|_| boxed(Palindrome)),
+        preceded(tag("times"), argument(2, "a count of at least 2")).map(|k| boxed(Times(k))),
+        preceded(tag("periodic"), argument(1, "a block length of at least 1"))
+            .map(|n| boxed(Periodic(n))),
+        preceded(char('!'), cut(factor)).map(|rule| boxed(Not(rule))),
+        delimited(
+            operator('('),
+            cut(rule),
+            cut(context(
+                "a closing bracket",
+                preceded(multispace0, char(')')),
+            )),
+        ),
+    ));
+    context("a rule", delimited(multispace0, factor, multispace0)).parse(input)
+}
+
+fn operator<'a>(c: char) -> impl FnMut(&'a str) -> IResult<'a, char> {
+    use nom::{
+        character::complete::{char, multispace0},
+        sequence::delimited,
+        Parser,
+    };
+    move |input| delimited(multispace0, char(c), multispace0).parse(input)
+}
+
+pub fn find_repeated_twice(range: [u64; 2]) -> Vec<u64> {
+    find_invalid(range, Policy::Twice)
+}
+
+pub fn find_repeated_any(range: [u64; 2]) -> Vec<u64> {
+    find_invalid(range, Policy::Any)
+}
+
+pub fn find_invalid(range: [u64; 2], policy: Policy) -> Vec<u64> {
+    find_invalid_radix(range, policy, 10)
+}
+
+// The ids in a..=b whose digits in the radix are a block repeated an allowed
+// number of times, in order. Rather than test every id in the range, this builds
+// the ids directly: an id of n digits made of an L digit block repeated is the
+// block times (r^n - 1) / (r^L - 1), which is 1 followed by L - 1 zeros, repeated.
+pub fn find_invalid_radix(range: [u64; 2], policy: Policy, radix: u32) -> Vec<u64> {
+    let [a, b] = range.map(u128::from);
+    let mut ids = Vec::new();
+    for digits in digit_count(a, radix)..=digit_count(b, radix) {
+        let blocks = (1..digits)
+            .filter(|block| digits.is_multiple_of(*block) && policy.allows(digits / block));
+        for block in blocks {
+            let (multiplier, first, last) = blocks_within(a, b, radix, digits, block);
+            ids.extend((first..=last).map(|x| (x * multiplier) as u64));
+        }
+    }
+    // an id such as 222222 is built from more than one block
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+pub fn count_invalid(range: [u64; 2], policy: Policy) -> u64 {
+    count_invalid_radix(range, policy, 10)
+}
+
+pub fn sum_invalid(range: [u64; 2], policy: Policy) -> u128 {
+    sum_invalid_radix(range, policy, 10)
+}
+
+pub fn count_invalid_radix(range: [u64; 2], policy: Policy, radix: u32) -> u64 {
+    tally(range, policy, radix).0 as u64
+}
+
+pub fn sum_invalid_radix(range: [u64; 2], policy: Policy, radix: u32) -> u128 {
+    tally(range, policy, radix).1 as u128
+}
+
+// The count and sum of the invalid ids in the range without listing them. The ids
+// from one block length are the multiplier times consecutive blocks, so they sum
+// as an arithmetic series.
+fn tally(range: [u64; 2], policy: Policy, radix: u32) -> (i128, i128) {
+    let [a, b] = range.map(u128::from);
+    let mut tally = (0, 0);
+    for digits in digit_count(a, radix)..=digit_count(b, radix) {
+        for (block, sign) in policy.terms(digits) {
+            let (multiplier, first, last) = blocks_within(a, b, radix, digits, block);
+            if first <= last {
+                let count = (last - first + 1) as i128;
+                let sum = multiplier as i128 * (first + last) as i128 * count / 2;
+                tally = (tally.0 + sign * count, tally.1 + sign * sum);
+            }
+        }
+    }
+    tally
+}
+
+// The multiplier for blocks of the given length repeated to fill the digits, and
+// the first and last blocks that give an id of that many digits in a..=b. The first
+// is past the last if there are none.
+fn blocks_within(a: u128, b: u128, radix: u32, digits: u32, block: u32) -> (u128, u128, u128) {
+    let radix = radix as u128;
+    let multiplier = (radix.pow(digits) - 1) / (radix.pow(block) - 1);
+    let first = a.div_ceil(multiplier).max(radix.pow(block - 1));
+    let last = (b / multiplier).min(radix.pow(block) - 1);
+    (multiplier, first, last)
+}
+
+fn digit_count(x: u128, radix: u32) -> u32 {
+    x.checked_ilog(radix as u128).unwrap_or(0) + 1
+}
+
+// Random comma separated ranges of ids with up to ten digits
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let ranges: Vec<_> = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.range(1..=10) as u32;
+            let a = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+            let b = a + rng.below(10_000);
+            format!("{}-{}", a, b)
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+// The ranges of ids to search in the radix they are written in: in the order given,
+// once reversed ones are swapped or left out, and merged so that no id is counted
+// twice. The reversed ranges are kept as given with their indices to warn about.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ranges {
+    pub radix: u32,
+    pub oriented: Vec<[u64; 2]>,
+    pub merged: Vec<[u64; 2]>,
+    pub reversed: Vec<(usize, [u64; 2])>,
+    pub handling: Reversed,
+}
+
+impl Ranges {
+    pub fn parse(text: &str, radix: u32, handling: Reversed) -> Result<Ranges, ParseError> {
+        let ranges = parse_ranges_radix(text, radix)?;
+        let (oriented, reversed) = orient(&ranges, handling);
+        Ok(Ranges {
+            radix,
+            merged: merge(&oriented),
+            oriented,
+            reversed: reversed.into_iter().map(|i| (i, ranges[i])).collect(),
+            handling,
+        })
+    }
+
+    // The sum of the ids invalid by the rule, each counted once
+    pub fn sum(&self, rule: &dyn IdRule) -> u128 {
+        self.merged.iter().map(|r| rule.sum(*r, self.radix)).sum()
+    }
+
+    // The sum range by range, counting an id again for each range it is in
+    pub fn raw_sum(&self, rule: &dyn IdRule) -> u128 {
+        self.oriented.iter().map(|r| rule.sum(*r, self.radix)).sum()
+    }
+
+    // What to report beside the sum by the rule: the raw sum, and both sums in the
+    // radix when it is not 10
+    pub fn details(&self, rule: &dyn IdRule, sum: u128) -> Vec<(String, String)> {
+        let raw = self.raw_sum(rule);
+        let mut details = vec![("raw".to_owned(), raw.to_string())];
+        if self.radix != 10 {
+            let radix = self.radix;
+            details.push((format!("in_base_{}", radix), format_radix(sum, radix)));
+            details.push((format!("raw_in_base_{}", radix), format_radix(raw, radix)));
+        }
+        details
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.reversed
+            .iter()
+            .map(|(i, range)| {
+                let [a, b] = range.map(|x| format_radix(x as u128, self.radix));
+                let action = match self.handling {
+                    Reversed::Swap => format!("reading it as {}-{}", b, a),
+                    Reversed::Skip => "skipping it".to_owned(),
+                };
+                format!("range {}, {}-{}, is reversed, {}", i + 1, a, b, action)
+            })
+            .collect()
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Ranges;
+    type Answer = u128;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        Self::parse_with(text, &Settings::default())
+    }
+
+    // The parts count each id once however many ranges it is in
+    fn parse_with(text: &str, settings: &Settings) -> Result<Self::Input, Error> {
+        Ok(Ranges::parse(text, settings.radix, settings.reversed)?)
+    }
+
+    fn part1(ranges: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(ranges.sum(&Policy::Twice))
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(ranges.sum(&Policy::Any))
+    }
+
+    fn warnings(ranges: &Self::Input) -> Vec<String> {
+        ranges.warnings()
+    }
+
+    fn details(ranges: &Self::Input, part: Part, sum: &Self::Answer) -> Vec<(String, String)> {
+        let policy = match part {
+            Part::One => Policy::Twice,
+            Part::Two => Policy::Any,
+        };
+        ranges.details(&policy, *sum)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::BufRead;
+
+    const CONTENT: &str = "\
+11-22,\
+95-115,\
+998-1012,\
+1188511880-1188511890,\
+222220-222224,\
+1698522-1698528,\
+446443-446449,\
+38593856-38593862,\
+565653-565659,\
+824824821-824824827,\
+2121212118-2121212124
+";
+
+    const RANGES: [[u64; 2]; 11] = [
+        [11, 22],
+        [95, 115],
+        [998, 1012],
+        [1188511880, 1188511890],
+        [222220, 222224],
+        [1698522, 1698528],
+        [446443, 446449],
+        [38593856, 38593862],
+        [565653, 565659],
+        [824824821, 824824827],
+        [2121212118, 2121212124],
+    ];
+
+    #[test]
+    fn test_parse_id_ranges() {
+        let buff = std::io::Cursor::new(CONTENT);
+        let ranges: Vec<_> = buff
+            .split(b',')
+            .map(|u| std::str::from_utf8(&u.unwrap()).unwrap().to_owned())
+            .map(|input| parse_range(&input).unwrap())
+            .collect();
+        assert_eq!(ranges, RANGES);
+    }
+
+    #[test]
+    fn test_repeates_every() {
+        assert!(repeats_every(&[1, 2, 3, 1, 2, 3], 3));
+        assert!(!repeats_every(&[1, 2, 3, 1, 2, 3, 1], 3));
+        assert!(repeats_every(&[1, 4, 4, 1, 4, 4, 1, 4, 4], 3));
+    }
+
+    #[test]
+    fn test_sample_part_1() {
+        let invalid_ids: Vec<_> = RANGES.into_iter().map(find_repeated_twice).collect();
+        assert_eq!(
+            invalid_ids,
+            [
+                vec![11, 22],
+                vec![99],
+                vec![1010],
+                vec![1188511885],
+                vec![222222],
+                vec![],
+                vec![446446],
+                vec![38593859],
+                vec![],
+                vec![],
+                vec![]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sample_part_2() {
+        let invalid_ids: Vec<_> = RANGES.into_iter().map(find_repeated_any).collect();
+        assert_eq!(
+            invalid_ids,
+            [
+                vec![11, 22],
+                vec![99, 111],
+                vec![999, 1010],
+                vec![1188511885],
+                vec![222222],
+                vec![],
+                vec![446446],
+                vec![38593859],
+                vec![565656],
+                vec![824824824],
+                vec![2121212121]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wide_range() {
+        let [a, b] = [10u64.pow(15), 10u64.pow(15) + 10u64.pow(12)];
+        let ids = find_repeated_any([a, b]);
+        assert_eq!(ids.len(), 10_000);
+        assert_eq!(ids.first(), Some(&1_000_000_010_000_000));
+        assert!(ids.iter().all(|x| (a..=b).contains(x)));
+        assert_eq!(find_repeated_twice([a, b]), ids);
+        let ids = find_repeated_any([0, 10u64.pow(10)]);
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(ids.last(), Some(&9_999_999_999));
+        let ids = find_repeated_twice([u64::MAX - 10u64.pow(9), u64::MAX]);
+        assert_eq!(ids, []);
+    }
+
+    #[test]
+    fn test_count_and_sum() {
+        for policy in [Policy::Twice, Policy::Any] {
+            for range in RANGES {
+                let ids = find_invalid(range, policy);
+                assert_eq!(count_invalid(range, policy), ids.len() as u64);
+                assert_eq!(
+                    sum_invalid(range, policy),
+                    ids.iter().map(|x| *x as u128).sum()
+                );
+            }
+        }
+        assert_eq!(count_invalid([222222, 222222], Policy::Any), 1);
+        // 6 digit ids repeat blocks of 3 or 2, and both counts include 111111 and co
+        let small = 9 + 9 + 90 + 9 + (900 + 90 - 9);
+        assert_eq!(count_invalid([1, 10u64.pow(6)], Policy::Any), small);
+        // 20 digit ids only fit up to the block 1844674407
+        let twice = (10u64.pow(9) - 1) + (1844674407 - 10u64.pow(9) + 1);
+        assert_eq!(count_invalid([0, u64::MAX], Policy::Twice), twice);
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day02::parse(CONTENT).unwrap();
+        assert_eq!(Day02::part1(&input).unwrap(), 1227775554);
+        assert_eq!(Day02::part2(&input).unwrap(), 4174379265);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let Err(Error::Parse(e)) = Day02::parse("11-22,\n3-,95-115") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.expected, "an id");
+        assert_eq!(e.snippet, "3-,95-115");
+    }
+
+    #[test]
+    fn test_radix() {
+        assert_eq!(parse_range_radix("0x1f-0x2A", 16).unwrap(), [31, 42]);
+        assert_eq!(parse_range_radix("1f-2a", 16).unwrap(), [31, 42]);
+        assert_eq!(parse_range_radix("0b101-0b1010", 2).unwrap(), [5, 10]);
+        assert_eq!(parse_range_radix("z-10", 36).unwrap(), [35, 36]);
+        let e = parse_range_radix("0x1f-0x2g", 16).unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (9, "end of input"));
+        let e = parse_range_radix("12-3", 2).unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (2, "'-'"));
+        for radix in [0, 1, 37, u32::MAX] {
+            let e = parse_range_radix("1-2", radix).unwrap_err();
+            assert_eq!((e.column, e.expected.as_str()), (1, "a radix from 2 to 36"));
+            assert!(parse_ranges_radix("1-2,3-4", radix).is_err());
+        }
+        assert_eq!(digits(0x1f, 16), [1, 15]);
+        assert_eq!(digits(0, 7), [0]);
+        assert_eq!(format_radix(0xabc, 16), "abc");
+        assert_eq!(format_radix(5, 2), "101");
+        // 0x11, 0x22 and so on to 0xff repeat a hex digit, 0x1010 to 0xffff a pair
+        let ids = find_invalid_radix([0, 0xffff], Policy::Twice, 16);
+        assert_eq!(ids.len(), 15 + 240);
+        assert!(ids.contains(&0xabab) && !ids.contains(&0xaba));
+        // of 0b10 to 0b111, only the ones made of all 1s repeat a block
+        let ids = find_invalid_radix([2, 7], Policy::Any, 2);
+        assert_eq!(ids, [0b11, 0b111]);
+    }
+
+    #[test]
+    fn test_rules() {
+        let invalid = |rule: &str, id: u64| {
+            parse_rule(rule)
+                .unwrap()
+                .is_invalid(&digits(id as u128, 10))
+        };
+        assert!(invalid("twice", 1212) && !invalid("twice", 121212));
+        assert!(invalid("any", 121212) && !invalid("any", 1213));
+        assert!(invalid("times(3)", 121212) && !invalid("times(3)", 1212));
+        assert!(invalid("times(2)", 1111) && invalid("times(4)", 1111));
+        assert!(invalid("palindrome", 12321) && invalid("palindrome", 7));
+        assert!(!invalid("palindrome", 1231));
+        assert!(invalid("periodic(2)", 121212) && !invalid("periodic(2)", 111));
+        assert!(invalid("periodic(1)", 111) && !invalid("periodic(1)", 7));
+        assert!(invalid("!twice", 121212) && !invalid("!twice", 1212));
+        // & binds tighter than |, and ! tighter than both
+        assert!(invalid("palindrome & any | twice", 1212));
+        assert!(!invalid("palindrome & (any | twice)", 1212));
+        assert!(invalid(" any & !palindrome ", 1212) && !invalid("any&!palindrome", 1111));
+        assert!(invalid("!!(twice)", 1212));
+    }
+
+    #[test]
+    fn test_rule_sums() {
+        let rule = parse_rule("periodic(2) | palindrome").unwrap();
+        let ids = rule.find([95, 1012], 10);
+        assert_eq!(ids[..4], [99, 101, 111, 121]);
+        assert_eq!(ids[ids.len() - 3..], [999, 1001, 1010]);
+        assert_eq!(
+            rule.sum([95, 1012], 10),
+            ids.iter().map(|x| *x as u128).sum()
+        );
+        // the policies count in closed form, and agree with testing every id
+        for policy in [Policy::Twice, Policy::Any] {
+            for range in RANGES {
+                let ids: Vec<_> = (range[0]..=range[1])
+                    .filter(|id| policy.is_invalid(&digits(*id as u128, 10)))
+                    .collect();
+                assert_eq!(policy.find(range, 10), ids);
+            }
+        }
+        // as does times(k), which agrees with twice for k = 2
+        for k in 1..=4 {
+            for range in RANGES {
+                let ids: Vec<_> = (range[0]..=range[1])
+                    .filter(|id| Times(k).is_invalid(&digits(*id as u128, 10)))
+                    .collect();
+                assert_eq!(Times(k).find(range, 10), ids);
+                assert_eq!(
+                    Times(k).sum(range, 10),
+                    ids.iter().map(|x| *x as u128).sum()
+                );
+            }
+        }
+        let range = [1, u64::MAX];
+        assert_eq!(Times(2).sum(range, 10), Policy::Twice.sum(range, 10));
+        assert_eq!(Times(3).sum([1, 1000], 10), 111 * 45);
+        assert_eq!(Times(64).find(range, 2), [u64::MAX]);
+        let input = Day02::parse(CONTENT).unwrap();
+        let sum = |rule: &str| -> u128 {
+            let rule = parse_rule(rule).unwrap();
+            input.merged.iter().map(|r| rule.sum(*r, 10)).sum()
+        };
+        assert_eq!(sum("twice"), 1227775554);
+        assert_eq!(sum("periodic(1)"), 4174379265);
+        assert_eq!(sum("any & !twice"), 4174379265 - 1227775554);
+    }
+
+    #[test]
+    fn test_rule_errors() {
+        let error = |rule| {
+            let e = parse_rule(rule).err().expect("an error");
+            (e.column, e.expected)
+        };
+        assert_eq!(error("thrice"), (1, "a rule".to_owned()));
+        assert_eq!(error("twice |"), (8, "a rule".to_owned()));
+        assert_eq!(error("times(1)"), (7, "a count of at least 2".to_owned()));
+        assert_eq!(
+            error("periodic(x)"),
+            (10, "a block length of at least 1".to_owned())
+        );
+        assert_eq!(error("(twice"), (7, "a closing bracket".to_owned()));
+        assert_eq!(error("times(3"), (8, "a closing bracket".to_owned()));
+        assert_eq!(error("twice any"), (7, "end of input".to_owned()));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let ranges = [
+            [95, 115],
+            [11, 22],
+            [20, 30],
+            [31, 40],
+            [50, 40],
+            [998, 1012],
+        ];
+        let (oriented, reversed) = orient(&ranges, Reversed::Swap);
+        assert_eq!(oriented[4], [40, 50]);
+        assert_eq!(reversed, [4]);
+        assert_eq!(merge(&oriented), [[11, 50], [95, 115], [998, 1012]]);
+        let (oriented, reversed) = orient(&ranges, Reversed::Skip);
+        assert_eq!(oriented.len(), 5);
+        assert_eq!(reversed, [4]);
+        assert_eq!(merge(&oriented), [[11, 40], [95, 115], [998, 1012]]);
+        let merged = merge(&[[5, u64::MAX], [0, 4], [7, 9]]);
+        assert_eq!(merged, [[0, u64::MAX]]);
+        assert_eq!(orient(&[], Reversed::Swap), (vec![], vec![]));
+        assert!(merge(&[]).is_empty());
+        // 11-22 and 20-30 share 22, which the raw sum counts twice
+        let (ranges, _) = orient(&[[11, 22], [20, 30], [22, 11]], Reversed::Swap);
+        let raw: u128 = ranges.iter().map(|r| sum_invalid(*r, Policy::Twice)).sum();
+        let deduplicated: u128 = merge(&ranges)
+            .iter()
+            .map(|r| sum_invalid(*r, Policy::Twice))
+            .sum();
+        assert_eq!((raw, deduplicated), (33 + 22 + 33, 33));
+    }
+
+    #[test]
+    fn test_solve_shared() {
+        // the runner swaps the reversed range with a warning, and counts 22 once
+        // in the answer but three times in the raw sum
+        let day = crate::runner::Day::find(2).unwrap();
+        let parts = [Part::One];
+        let details = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            let pairs = pairs.iter();
+            pairs.map(|(k, v)| (k.to_string(), v.to_string())).collect()
+        };
+        let solved = day
+            .solve("11-22,20-30,22-11", &parts, &Settings::default())
+            .unwrap();
+        assert_eq!(
+            solved.warnings,
+            ["range 3, 22-11, is reversed, reading it as 11-22"]
+        );
+        assert_eq!(solved.answers[0].value.as_deref().unwrap(), "33");
+        assert_eq!(solved.answers[0].details, details(&[("raw", "88")]));
+        let settings = Settings {
+            reversed: Reversed::Skip,
+            ..Settings::default()
+        };
+        let solved = day.solve("11-22,20-30,22-11", &parts, &settings).unwrap();
+        assert_eq!(
+            solved.warnings,
+            ["range 3, 22-11, is reversed, skipping it"]
+        );
+        assert_eq!(solved.answers[0].details, details(&[("raw", "55")]));
+        assert_eq!(Day02::parse("22-11,20-30").unwrap().merged, [[11, 30]]);
+        // ids in another radix are summed the same way, and the sums given in it too
+        let settings = Settings {
+            radix: 16,
+            ..Settings::default()
+        };
+        let solved = day.solve("0x10-0x22,0x22-0x22", &parts, &settings).unwrap();
+        let answer = &solved.answers[0];
+        assert!(solved.warnings.is_empty());
+        assert_eq!(answer.value.as_deref().unwrap(), (0x11 + 0x22).to_string());
+        assert_eq!(
+            answer.details,
+            details(&[
+                ("raw", "85"),
+                ("in_base_16", "33"),
+                ("raw_in_base_16", "55")
+            ])
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // twenty ranges of fewer than 10000 ids that start with up to ten digits
+        let ranges = parse_ranges_radix(text.trim_end(), 10).unwrap();
+        assert_eq!(ranges.len(), 20);
+        assert!(ranges
+            .iter()
+            .all(|[a, b]| *a < 10u64.pow(10) && a <= b && b - a < 10_000));
+    }
+}
@@ -0,0 +1,151 @@
+// Advent of Code 2025 Day 3
+// A. Drew
+
+use crate::rng::Rng;
+use crate::{Error, ParseError, Solution};
+
+// Parse a string of digits into a vector of integers
+pub fn parse_bank(line: &str) -> Result<Vec<u64>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(u64::from)
+                .ok_or_else(|| ParseError::at_line(1, line, i, "a battery digit"))
+        })
+        .collect()
+}
+
+// Find the largest joltage using the provided number of batteries in the bank
+pub fn largest_joltage(batteries: u32, bank: &[u64]) -> Option<u64> {
+    (0..batteries)
+        .rev()
+        .try_fold((0, 0), |(joltage, begin), rank| {
+            find_max_battery(begin, rank, bank).map(|(a, i)| (joltage + a * 10u64.pow(rank), i + 1))
+        })
+        .map(|(joltage, _)| joltage)
+}
+
+// Finds the value and index of the maximum battery while making sure there are at least
+// rank more batteries to the right
+pub fn find_max_battery(begin: usize, rank: u32, bank: &[u64]) -> Option<(u64, usize)> {
+    // there must be rank batteries left after the chosen one
+    let end = bank.len().checked_sub(rank as usize)?;
+    bank.get(begin..end)?
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by(|(_, x), (_, y)| x.cmp(y))
+        .map(|(i, a)| (*a, begin + i))
+}
+
+// Random banks of 100 batteries rated 1 to 9
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let bank: String = (0..100)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect();
+            bank + "\n"
+        })
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u64>>;
+    type Answer = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        text.lines()
+            .enumerate()
+            .map(|(i, line)| parse_bank(line).map_err(|e| e.on_line(i + 1).into()))
+            .collect()
+    }
+
+    fn part1(banks: &Self::Input) -> Result<Self::Answer, Error> {
+        total_joltage(2, banks)
+    }
+
+    fn part2(banks: &Self::Input) -> Result<Self::Answer, Error> {
+        total_joltage(12, banks)
+    }
+}
+
+fn total_joltage(batteries: u32, banks: &[Vec<u64>]) -> Result<u64, Error> {
+    banks
+        .iter()
+        .map(|bank| largest_joltage(batteries, bank).ok_or(Error::NoAnswer))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    const BANKS: [[u64; 15]; 4] = [
+        [9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+        [8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+        [2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+        [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+    ];
+
+    #[test]
+    fn test_parsing() {
+        let banks: Result<Vec<_>, _> = CONTENT.lines().map(parse_bank).collect();
+        assert_eq!(banks.unwrap(), BANKS);
+    }
+
+    #[test]
+    fn test_sample_with_2() {
+        let joltages: Vec<_> = BANKS.iter().map(|bank| largest_joltage(2, bank)).collect();
+        assert_eq!(joltages, [Some(98), Some(89), Some(78), Some(92)]);
+    }
+
+    #[test]
+    fn test_sample_with_12() {
+        let joltages: Vec<_> = BANKS.iter().map(|bank| largest_joltage(12, bank)).collect();
+        assert_eq!(
+            joltages,
+            [
+                Some(987654321111),
+                Some(811111111119),
+                Some(434234234278),
+                Some(888911112111)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day03::parse(CONTENT).unwrap();
+        assert_eq!(Day03::part1(&input).unwrap(), 357);
+        assert_eq!(Day03::part2(&input).unwrap(), 3121910778619);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let Err(Error::Parse(e)) = Day03::parse("987654321111111\n8111x1111111119\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (2, 5));
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // twenty banks of 100 batteries rated 1 to 9
+        let banks = Day03::parse(&text).unwrap();
+        assert_eq!(banks.len(), 20);
+        assert!(banks
+            .iter()
+            .all(|bank| bank.len() == 100 && bank.iter().all(|x| (1..=9).contains(x))));
+    }
+}
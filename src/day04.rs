@@ -0,0 +1,279 @@
+// Advent of Code 2025 Day 4
+// A. Drew
+
+use crate::rng::Rng;
+use crate::{Error, ParseError, Solution};
+
+pub fn parse_rolls(text: &str) -> Result<Vec<[isize; 2]>, ParseError> {
+    text.lines()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            line.char_indices()
+                .enumerate()
+                .filter_map(move |(j, (offset, c))| match c {
+                    '@' => Some(Ok([i as isize, j as isize])),
+                    '.' => None,
+                    _ => Some(Err(ParseError::at_line(i + 1, line, offset, "'@' or '.'"))),
+                })
+        })
+        .collect()
+}
+
+// return the accessible rolls that were removed and the remaining rolls
+pub fn partition_accessible(rolls: &[[isize; 2]]) -> (Vec<[isize; 2]>, Vec<[isize; 2]>) {
+    rolls.iter().partition(|[i, j]| {
+        let neighbors = (i - 1..=i + 1)
+            .flat_map(|ii| (j - 1..=j + 1).map(move |jj| [ii, jj]))
+            .filter(|x| rolls.binary_search(x).is_ok())
+            .count();
+        neighbors < (4 + 1)
+    })
+}
+
+// Repeatedly remove the accessible rolls and return how many were removed in total
+pub fn count_removable(rolls: Vec<[isize; 2]>) -> usize {
+    use std::ops::ControlFlow;
+
+    std::iter::repeat(())
+        .try_fold((0, rolls), |(cnt, rolls), _| {
+            let (removed, remaining) = partition_accessible(&rolls);
+            let n = removed.len();
+            if n == 0 {
+                ControlFlow::Break(cnt)
+            } else {
+                ControlFlow::Continue((cnt + n, remaining))
+            }
+        })
+        .break_value()
+        .expect("repeat never ends")
+}
+
+// A random square grid with the given side and rolls in about 60% of places
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<[isize; 2]>;
+    type Answer = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        Ok(parse_rolls(text)?)
+    }
+
+    fn part1(rolls: &Self::Input) -> Result<Self::Answer, Error> {
+        let (accessible, _) = partition_accessible(rolls);
+        Ok(accessible.len())
+    }
+
+    fn part2(rolls: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(count_removable(rolls.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    const ROLLS: [[isize; 2]; 71] = [
+        [0, 2],
+        [0, 3],
+        [0, 5],
+        [0, 6],
+        [0, 7],
+        [0, 8],
+        [1, 0],
+        [1, 1],
+        [1, 2],
+        [1, 4],
+        [1, 6],
+        [1, 8],
+        [1, 9],
+        [2, 0],
+        [2, 1],
+        [2, 2],
+        [2, 3],
+        [2, 4],
+        [2, 6],
+        [2, 8],
+        [2, 9],
+        [3, 0],
+        [3, 2],
+        [3, 3],
+        [3, 4],
+        [3, 5],
+        [3, 8],
+        [4, 0],
+        [4, 1],
+        [4, 3],
+        [4, 4],
+        [4, 5],
+        [4, 6],
+        [4, 8],
+        [4, 9],
+        [5, 1],
+        [5, 2],
+        [5, 3],
+        [5, 4],
+        [5, 5],
+        [5, 6],
+        [5, 7],
+        [5, 9],
+        [6, 1],
+        [6, 3],
+        [6, 5],
+        [6, 7],
+        [6, 8],
+        [6, 9],
+        [7, 0],
+        [7, 2],
+        [7, 3],
+        [7, 4],
+        [7, 6],
+        [7, 7],
+        [7, 8],
+        [7, 9],
+        [8, 1],
+        [8, 2],
+        [8, 3],
+        [8, 4],
+        [8, 5],
+        [8, 6],
+        [8, 7],
+        [8, 8],
+        [9, 0],
+        [9, 2],
+        [9, 4],
+        [9, 5],
+        [9, 6],
+        [9, 8],
+    ];
+
+    #[test]
+    fn test_parsing() {
+        let rolls = parse_rolls(CONTENT).unwrap();
+        assert_eq!(rolls, ROLLS);
+        let e = parse_rolls("..@@.\n@@#@.\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+    }
+
+    #[test]
+    fn test_sample() {
+        let (accessible, _) = partition_accessible(&ROLLS);
+        assert_eq!(
+            accessible,
+            [
+                [0, 2],
+                [0, 3],
+                [0, 5],
+                [0, 6],
+                [0, 8],
+                [1, 0],
+                [2, 6],
+                [4, 0],
+                [4, 9],
+                [7, 0],
+                [9, 0],
+                [9, 2],
+                [9, 8]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sample_1() {
+        let (accessible, left) = partition_accessible(&ROLLS);
+        assert_eq!(accessible.len(), 13);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 12);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 7);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 5);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 2);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 1);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 1);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 1);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 1);
+        let (accessible, left) = partition_accessible(&left);
+        assert_eq!(accessible.len(), 0);
+        assert_eq!(
+            left,
+            [
+                [3, 4],
+                [3, 5],
+                [4, 3],
+                [4, 4],
+                [4, 5],
+                [4, 6],
+                [5, 3],
+                [5, 4],
+                [5, 5],
+                [5, 6],
+                [5, 7],
+                [6, 3],
+                [6, 5],
+                [6, 7],
+                [6, 8],
+                [7, 3],
+                [7, 4],
+                [7, 6],
+                [7, 7],
+                [7, 8],
+                [8, 3],
+                [8, 4],
+                [8, 5],
+                [8, 6],
+                [8, 7],
+                [9, 4],
+                [9, 5],
+                [9, 6],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day04::parse(CONTENT).unwrap();
+        assert_eq!(Day04::part1(&input).unwrap(), 13);
+        assert_eq!(Day04::part2(&input).unwrap(), 43);
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // a 20 by 20 grid with rolls in about 60% of its 400 places
+        assert!(text.lines().all(|line| line.len() == 20));
+        assert_eq!(text.lines().count(), 20);
+        let rolls = Day04::parse(&text).unwrap();
+        assert!((160..=320).contains(&rolls.len()));
+        assert!(rolls.iter().flatten().all(|x| (0..20).contains(x)));
+    }
+}
@@ -0,0 +1,190 @@
+// Advent of Code 2025 Day 5
+// A. Drew
+
+use crate::parse::{parse_all, IResult};
+use crate::rng::Rng;
+use crate::{Error, ParseError, Solution};
+use nom::{
+    character::complete::{char, digit1, multispace0, newline},
+    combinator::{cut, map_res},
+    error::context,
+    multi::{count, separated_list1},
+    sequence::{separated_pair, terminated},
+    Parser,
+};
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+// Once a range has started it must be complete
+fn parse_frash_range(input: &str) -> IResult<'_, RangeInclusive<u64>> {
+    separated_pair(number, cut(char('-')), cut(number))
+        .map(|(a, b)| a..=b)
+        .parse(input)
+}
+
+fn number(input: &str) -> IResult<'_, u64> {
+    context("a number", map_res(digit1, str::parse)).parse(input)
+}
+
+fn parse_fresh_ranges(input: &str) -> IResult<'_, Vec<RangeInclusive<u64>>> {
+    separated_list1(newline, parse_frash_range).parse(input)
+}
+
+fn parse_ingredients(input: &str) -> IResult<'_, Vec<u64>> {
+    separated_list1(newline, number).parse(input)
+}
+
+pub fn parse_database(input: &str) -> Result<(Vec<RangeInclusive<u64>>, Vec<u64>), ParseError> {
+    let database = separated_pair(parse_fresh_ranges, count(newline, 2), parse_ingredients);
+    parse_all(input, terminated(database, multispace0))
+}
+
+pub fn is_fresh(fresh: &[RangeInclusive<u64>], ingredient: u64) -> bool {
+    fresh.iter().any(|range| range.contains(&ingredient))
+}
+
+pub fn fresh_ingredients(fresh: &[RangeInclusive<u64>], ingredients: &[u64]) -> Vec<u64> {
+    ingredients
+        .iter()
+        .filter(|x| is_fresh(fresh, **x))
+        .copied()
+        .collect()
+}
+
+fn overlaps(r0: &RangeInclusive<u64>, r1: &RangeInclusive<u64>) -> bool {
+    r0.contains(r1.start()) || r0.contains(r1.end())
+}
+
+fn merge_overlapping(ranges: &[RangeInclusive<u64>]) -> Option<RangeInclusive<u64>> {
+    ranges.iter().cloned().reduce(|acc, r| {
+        let start = min(*acc.start(), *r.start());
+        let end = max(*acc.end(), *r.end());
+        start..=end
+    })
+}
+
+pub fn count_elements(range: &RangeInclusive<u64>) -> u64 {
+    range.end() - range.start() + 1
+}
+
+pub fn merge_ranges(fresh: &[RangeInclusive<u64>]) -> HashSet<RangeInclusive<u64>> {
+    fresh
+        .iter()
+        .fold(HashSet::from_iter(fresh.iter().cloned()), |mut acc, r| {
+            let overlapping: Vec<_> = acc.extract_if(|x| overlaps(r, x)).collect();
+            if let Some(merged) = merge_overlapping(&overlapping) {
+                acc.insert(merged);
+            }
+            acc
+        })
+}
+
+// Random fresh ranges of ids up to 15 digits, wide enough that some overlap,
+// followed by five times as many ingredients
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const MAX: u64 = 999_999_999_999_999;
+    let size = size.max(1);
+    let width = 2 * MAX / size as u64;
+    let ranges = (0..size).map(|_| {
+        let a = rng.range(1..=MAX);
+        format!("{}-{}\n", a, a + rng.below(width + 1))
+    });
+    let mut text: String = ranges.collect();
+    text.push('\n');
+    for _ in 0..5 * size {
+        text += &format!("{}\n", rng.range(1..=MAX));
+    }
+    text
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<RangeInclusive<u64>>, Vec<u64>);
+    type Answer = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        Ok(parse_database(text)?)
+    }
+
+    fn part1((fresh, ingredients): &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(fresh_ingredients(fresh, ingredients).len() as u64)
+    }
+
+    fn part2((fresh, _): &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(merge_ranges(fresh).iter().map(count_elements).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    const FRESH: [RangeInclusive<u64>; 4] = [(3..=5), (10..=14), (16..=20), (12..=18)];
+
+    const IDS: [u64; 6] = [1, 5, 8, 11, 17, 32];
+
+    #[test]
+    fn test_ranges() {
+        let fresh: Vec<_> = IDS
+            .iter()
+            .filter(|x| is_fresh(&FRESH, **x))
+            .copied()
+            .collect();
+        assert_eq!(fresh, [5, 11, 17]);
+    }
+
+    #[test]
+    fn test_parsing() {
+        let (ranges, ingredients) = parse_database(TEXT).unwrap();
+        assert_eq!(ranges, FRESH);
+        assert_eq!(ingredients, IDS);
+    }
+
+    #[test]
+    fn test_all_fresh() {
+        let ans = merge_ranges(&FRESH);
+        assert_eq!(ans, HashSet::from([3..=5, 10..=20]));
+        assert_eq!(ans.iter().map(count_elements).sum::<u64>(), 14);
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day05::parse(TEXT).unwrap();
+        assert_eq!(Day05::part1(&input).unwrap(), 3);
+        assert_eq!(Day05::part2(&input).unwrap(), 14);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_database("3-5\n10-\n\n1\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.expected, "a number");
+        let e = parse_database("3-5\n\n1\n5x\n").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 2));
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // twenty ranges then a hundred ingredients
+        let (ranges, ingredients) = Day05::parse(&text).unwrap();
+        assert_eq!((ranges.len(), ingredients.len()), (20, 100));
+        assert!(ranges.iter().all(|range| range.start() <= range.end()));
+    }
+}
@@ -0,0 +1,324 @@
+// Advent of Code 2025 Day 6
+// A. Drew
+
+use crate::rng::Rng;
+use crate::{Error, ParseError, Solution};
+use std::ops::{Add, Mul};
+
+pub type Op = fn(u64, u64) -> u64;
+
+// operand_lines must not be empty
+pub fn solve_rows(
+    operators: &[(usize, usize, Op)],
+    operand_lines: &[&str],
+) -> Result<Vec<u64>, ParseError> {
+    operators
+        .iter()
+        .map(|(i, w, op)| {
+            let operands = operand_lines
+                .iter()
+                .enumerate()
+                .map(|(row, line)| {
+                    let digits: String = line.chars().skip(*i).take(*w).collect();
+                    digits
+                        .trim()
+                        .parse()
+                        .map_err(|_| error_at(operand_lines, row, *i, "a number"))
+                })
+                .collect::<Result<Vec<u64>, _>>()?;
+            Ok(operands.into_iter().reduce(op).expect("at least one line"))
+        })
+        .collect()
+}
+
+// operand_lines must not be empty
+pub fn solve_cols(
+    operators: &[(usize, usize, Op)],
+    operand_lines: &[&str],
+) -> Result<Vec<u64>, ParseError> {
+    operators
+        .iter()
+        .map(|(i, w, op)| {
+            let operands = (*i..i + w)
+                .map(|column| {
+                    // lines may be trimmed, so anything past the end is blank
+                    let digits: String = operand_lines
+                        .iter()
+                        .map(|line| line.chars().nth(column).unwrap_or(' '))
+                        .collect();
+                    digits.trim().parse().map_err(|_| {
+                        let row = digits
+                            .chars()
+                            .position(|c| !c.is_ascii_digit() && c != ' ')
+                            .unwrap_or(0);
+                        error_at(operand_lines, row, column, "a digit")
+                    })
+                })
+                .collect::<Result<Vec<u64>, _>>()?;
+            Ok(operands
+                .into_iter()
+                .reduce(op)
+                .expect("at least one column"))
+        })
+        .collect()
+}
+
+// Check that the operands read as numbers both ways, so that neither part can fail
+// on them: every character is a digit or a space, and within each problem the
+// digits of every row and of every column are unbroken
+pub fn check_operands(
+    operators: &[(usize, usize, Op)],
+    operand_lines: &[&str],
+) -> Result<(), ParseError> {
+    let grid: Vec<Vec<char>> = operand_lines
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect();
+    for (row, line) in grid.iter().enumerate() {
+        if let Some(column) = line.iter().position(|c| !c.is_ascii_digit() && *c != ' ') {
+            return Err(error_at(operand_lines, row, column, "a digit"));
+        }
+    }
+    // lines may be trimmed, so anything past the end is blank
+    let cell = |row: usize, column: usize| grid[row].get(column).copied().unwrap_or(' ');
+    let unbroken = |cells: String| {
+        let digits = cells.trim();
+        !digits.is_empty() && !digits.contains(' ')
+    };
+    for (i, w, _) in operators {
+        for row in 0..grid.len() {
+            if !unbroken((*i..i + w).map(|column| cell(row, column)).collect()) {
+                return Err(error_at(operand_lines, row, *i, "a number"));
+            }
+        }
+        for column in *i..i + w {
+            if !unbroken((0..grid.len()).map(|row| cell(row, column)).collect()) {
+                return Err(error_at(operand_lines, 0, column, "a number"));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Point at a character of an operand line, which are the first lines of the worksheet
+fn error_at(operand_lines: &[&str], row: usize, column: usize, expected: &str) -> ParseError {
+    let line = operand_lines[row];
+    let offset = line
+        .char_indices()
+        .nth(column)
+        .map_or(line.len(), |(i, _)| i);
+    ParseError::at_line(row + 1, line, offset, expected)
+}
+
+pub fn parse_operators(input: &str) -> Result<Vec<(usize, usize, Op)>, ParseError> {
+    input
+        .char_indices()
+        .try_fold(Vec::<(usize, usize, Op)>::new(), |mut acc, (i, c)| {
+            match c {
+                '*' => {
+                    if let Some((_, w, _)) = acc.last_mut() {
+                        *w -= 1;
+                    }
+                    acc.push((i, 1, u64::mul));
+                }
+                '+' => {
+                    if let Some((_, w, _)) = acc.last_mut() {
+                        *w -= 1;
+                    }
+                    acc.push((i, 1, u64::add));
+                }
+                ' ' => {
+                    if let Some((_, w, _)) = acc.last_mut() {
+                        *w += 1;
+                    }
+                }
+                _ => return Err(ParseError::at_line(1, input, i, "'*', '+' or ' '")),
+            }
+            Ok(acc)
+        })
+}
+
+// Random problems of four operands with up to four digits, each aligned to the
+// left or right of its columns. Operand lengths only grow or only shrink down a
+// problem so that no column has a gap between its digits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const ROWS: usize = 4;
+    let problems: Vec<_> = (0..size.max(1))
+        .map(|_| {
+            let mut lengths: Vec<_> = (0..ROWS).map(|_| rng.range(1..=4) as u32).collect();
+            lengths.sort();
+            if rng.chance(0.5) {
+                lengths.reverse();
+            }
+            let operands: Vec<String> = lengths
+                .iter()
+                .map(|digits| {
+                    rng.range(10u64.pow(digits - 1)..=10u64.pow(*digits) - 1)
+                        .to_string()
+                })
+                .collect();
+            let width = operands.iter().map(String::len).max().unwrap_or(1);
+            let left = rng.chance(0.5);
+            let cells: Vec<_> = operands
+                .iter()
+                .map(|x| {
+                    if left {
+                        format!("{:<width$}", x)
+                    } else {
+                        format!("{:>width$}", x)
+                    }
+                })
+                .collect();
+            (cells, format!("{:<width$}", rng.choose(&['*', '+'])))
+        })
+        .collect();
+    let mut lines: Vec<String> = (0..ROWS)
+        .map(|row| {
+            let cells: Vec<_> = problems
+                .iter()
+                .map(|(cells, _)| cells[row].as_str())
+                .collect();
+            cells.join(" ")
+        })
+        .collect();
+    let operators: Vec<_> = problems.iter().map(|(_, op)| op.as_str()).collect();
+    lines.push(operators.join(" "));
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+pub struct Day06;
+
+pub struct Worksheet {
+    operators: Vec<(usize, usize, Op)>,
+    operand_lines: Vec<String>,
+}
+
+impl Worksheet {
+    fn operand_lines(&self) -> Vec<&str> {
+        self.operand_lines.iter().map(String::as_str).collect()
+    }
+}
+
+impl Solution for Day06 {
+    type Input = Worksheet;
+    type Answer = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        let lines: Vec<_> = text.lines().collect();
+        let (operator_line, operand_lines) = match lines.split_last() {
+            Some((_, [])) | None => {
+                return Err(
+                    ParseError::at(text, text, "operand lines then an operator line").into(),
+                )
+            }
+            Some(split) => split,
+        };
+        let operators = parse_operators(operator_line).map_err(|e| e.on_line(lines.len()))?;
+        check_operands(&operators, operand_lines)?;
+        Ok(Worksheet {
+            operators,
+            operand_lines: operand_lines.iter().map(|line| line.to_string()).collect(),
+        })
+    }
+
+    fn part1(worksheet: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(
+            solve_rows(&worksheet.operators, &worksheet.operand_lines())?
+                .iter()
+                .sum(),
+        )
+    }
+
+    fn part2(worksheet: &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(
+            solve_cols(&worksheet.operators, &worksheet.operand_lines())?
+                .iter()
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "\
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+    #[test]
+    fn test_paser_operators() {
+        let line = "*   +    *   +  ";
+        let ops = parse_operators(line).unwrap();
+        assert_eq!(ops.len(), 4);
+        let os: Vec<_> = ops.iter().map(|(i, w, _)| (*i, *w)).collect();
+        assert_eq!(os, [(0, 3), (4, 4), (9, 3), (13, 3)]);
+    }
+
+    #[test]
+    fn test_solve_rows() {
+        let lines: Vec<_> = TEXT.lines().collect();
+        let (operator_line, operand_lines) = lines.split_last().unwrap();
+        let operators = parse_operators(operator_line).unwrap();
+        let ans = solve_rows(&operators, operand_lines).unwrap();
+        assert_eq!(ans, [33210, 490, 4243455, 401]);
+    }
+
+    #[test]
+    fn test_solve_cols() {
+        let lines: Vec<_> = TEXT.lines().collect();
+        let (operator_line, operand_lines) = lines.split_last().unwrap();
+        let operators = parse_operators(operator_line).unwrap();
+        let ans = solve_cols(&operators, operand_lines).unwrap();
+        assert_eq!(ans, [8544, 625, 3253600, 1058]);
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day06::parse(TEXT).unwrap();
+        assert_eq!(Day06::part1(&input).unwrap(), 4277556);
+        assert_eq!(Day06::part2(&input).unwrap(), 3263827);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        // malformed operands are found by parsing rather than by either part
+        let error = |text| match Day06::parse(text) {
+            Err(Error::Parse(e)) => (e.line, e.column, e.expected),
+            _ => panic!("expected a parse error"),
+        };
+        assert_eq!(error("12 3\n4x 5\n*  +\n"), (2, 2, "a digit".to_owned()));
+        assert_eq!(
+            error("12 4\n 2 5\n3  6\n*  +\n"),
+            (1, 1, "a number".to_owned())
+        );
+        assert_eq!(
+            error("1 2 3\n456 7\n*   +\n"),
+            (1, 1, "a number".to_owned())
+        );
+        assert_eq!(error("12 3\n   5\n*  +\n"), (2, 1, "a number".to_owned()));
+        // the parts still check operands they are given directly
+        let lines = ["12 3", "4x 5"];
+        let operators = parse_operators("*  +").unwrap();
+        let e = solve_rows(&operators, &lines).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = solve_cols(&operators, &lines).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+        let Err(Error::Parse(e)) = Day06::parse("12 3\n*  -\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (2, 4));
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // twenty problems of four operands
+        let worksheet = Day06::parse(&text).unwrap();
+        assert_eq!(worksheet.operators.len(), 20);
+        assert_eq!(worksheet.operand_lines.len(), 4);
+    }
+}
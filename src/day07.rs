@@ -0,0 +1,228 @@
+// Advent of Code 2025 Day 7
+// A. Drew
+
+use crate::rng::Rng;
+use crate::{Error, ParseError, Solution};
+
+pub fn parse_input(text: &str) -> Result<(isize, Vec<Vec<isize>>), ParseError> {
+    let lines: Vec<_> = text.lines().collect();
+    let (first, rest) = lines.split_first().unwrap_or((&"", &[]));
+    let initial_beam =
+        find_initial_beam(first).ok_or_else(|| ParseError::at_line(1, first, 0, "a beam 'S'"))?;
+    let splitters = parse_splitters(rest).map_err(|e| {
+        let line = e.line + 1;
+        e.on_line(line)
+    })?;
+    Ok((initial_beam, splitters))
+}
+
+pub fn find_initial_beam(line: &str) -> Option<isize> {
+    line.chars()
+        .position(|c| c == 'S')
+        .and_then(|i| isize::try_from(i).ok())
+}
+
+pub fn parse_splitters(lines: &[&str]) -> Result<Vec<Vec<isize>>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(n, line)| {
+            line.char_indices()
+                .filter_map(|(i, c)| match c {
+                    '^' => isize::try_from(i).ok().map(Ok),
+                    '.' => None,
+                    _ => Some(Err(ParseError::at_line(n + 1, line, i, "'^' or '.'"))),
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+pub fn count_timelines<T>(initial_beam: &isize, splitters: &[T]) -> usize
+where
+    T: AsRef<[isize]>,
+{
+    let beams = splitters
+        .iter()
+        .fold(vec![(*initial_beam, 1)], |beams, splitters| {
+            let (_, beams) = split_beams(&beams, splitters.as_ref());
+            beams
+        });
+    beams.iter().map(|(_, cnt)| cnt).sum()
+}
+
+pub fn count_splits<T>(initial_beam: &isize, splitters: &[T]) -> usize
+where
+    T: AsRef<[isize]>,
+{
+    let (sum, _) =
+        splitters
+            .iter()
+            .fold((0, vec![(*initial_beam, 1)]), |(sum, beams), splitters| {
+                let (n, beams) = split_beams(&beams, splitters.as_ref());
+                (sum + n, beams)
+            });
+    sum
+}
+
+pub fn split_beams(beams: &[(isize, usize)], splitters: &[isize]) -> (usize, Vec<(isize, usize)>) {
+    let mut splits = Vec::new();
+    let mut beams: Vec<_> = beams
+        .iter()
+        .flat_map(|b @ (pos, _)| {
+            if splitters.binary_search(pos).is_ok() {
+                splits.push(pos);
+                split_beam(b).to_vec()
+            } else {
+                vec![*b]
+            }
+        })
+        .collect();
+    splits.sort();
+    splits.dedup();
+    beams.sort();
+    beams = beams
+        .chunk_by(|a, b| a == b)
+        .map(|c| {
+            (
+                c.first().expect("chunk has at least one element").0,
+                c.iter().map(|(_, n)| n).sum(),
+            )
+        })
+        .collect();
+    (splits.len(), beams)
+}
+
+fn split_beam((pos, cnt): &(isize, usize)) -> [(isize, usize); 2] {
+    [(pos - 1, *cnt), (pos + 1, *cnt)]
+}
+
+// A square manifold with the given width, the beam entering in the middle
+// and splitters scattered along every other row
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(1);
+    let first: String = (0..width)
+        .map(|i| if i == width / 2 { 'S' } else { '.' })
+        .collect();
+    let rows = (1..width).map(|row| -> String {
+        (0..width)
+            .map(|_| {
+                if row % 2 == 0 && rng.chance(0.3) {
+                    '^'
+                } else {
+                    '.'
+                }
+            })
+            .collect()
+    });
+    std::iter::once(first)
+        .chain(rows)
+        .map(|line| line + "\n")
+        .collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = (isize, Vec<Vec<isize>>);
+    type Answer = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(text)?)
+    }
+
+    fn part1((initial_beam, splitters): &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(count_splits(initial_beam, splitters))
+    }
+
+    fn part2((initial_beam, splitters): &Self::Input) -> Result<Self::Answer, Error> {
+        Ok(count_timelines(initial_beam, splitters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    const INITIAL_BEAM: isize = 7;
+
+    const SPLITTERS: [&[isize]; 15] = [
+        &[],
+        &[7],
+        &[],
+        &[6, 8],
+        &[],
+        &[5, 7, 9],
+        &[],
+        &[4, 6, 10],
+        &[],
+        &[3, 5, 9, 11],
+        &[],
+        &[2, 6, 12],
+        &[],
+        &[1, 3, 5, 7, 9, 13],
+        &[],
+    ];
+
+    #[test]
+    fn test_count_splits() {
+        assert_eq!(count_splits(&INITIAL_BEAM, &SPLITTERS), 21);
+    }
+
+    #[test]
+    fn test_count_timelines() {
+        assert_eq!(count_timelines(&INITIAL_BEAM, &SPLITTERS), 40);
+    }
+
+    #[test]
+    fn test_parse() {
+        let (initial_beam, splitters) = parse_input(TEXT).unwrap();
+        assert_eq!(initial_beam, INITIAL_BEAM);
+        assert_eq!(splitters, SPLITTERS);
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = Day07::parse(TEXT).unwrap();
+        assert_eq!(Day07::part1(&input).unwrap(), 21);
+        assert_eq!(Day07::part2(&input).unwrap(), 40);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("...\n.^.\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        assert_eq!(e.expected, "a beam 'S'");
+        let e = parse_input(".S.\n...\n.^v\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 3));
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // a 20 by 20 manifold with the beam entering in the middle
+        assert!(text.lines().all(|line| line.len() == 20));
+        assert_eq!(text.lines().count(), 20);
+        let (initial_beam, _) = Day07::parse(&text).unwrap();
+        assert_eq!(initial_beam, 10);
+    }
+}
@@ -0,0 +1,230 @@
+// Advent of Code 2025 Day 8
+// A. Drew
+
+use crate::rng::Rng;
+use crate::{Error, ParseError, Solution};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeSet, BinaryHeap};
+use std::iter::zip;
+
+pub type Node = [i64; 3];
+pub type Weight = i64;
+
+#[derive(Clone, Copy)]
+pub struct Edge(pub Weight, pub [Node; 2]);
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Edge {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Edge {}
+
+// Connect the n shortest nodes
+pub fn connect_n(edges: &mut BinaryHeap<Reverse<Edge>>, n: usize) -> Vec<BTreeSet<Node>> {
+    let mut circuits: Vec<BTreeSet<Node>> = Vec::new();
+    for _ in 0..n {
+        if let Some(Reverse(Edge(_, nodes))) = edges.pop() {
+            circuits = add_edge(&circuits, &nodes);
+        }
+    }
+    circuits
+}
+
+pub fn add_edge(circuits: &[BTreeSet<Node>], [a, b]: &[Node; 2]) -> Vec<BTreeSet<Node>> {
+    let (mut with, mut without): (Vec<_>, Vec<_>) = circuits
+        .iter()
+        .cloned()
+        .partition(|x| x.contains(a) || x.contains(b));
+    with.push(BTreeSet::from([*a, *b]));
+    let merged = with
+        .iter()
+        .fold(BTreeSet::new(), |acc, x| acc.union(x).cloned().collect());
+    without.push(merged);
+    without
+}
+
+// Get a min-heap of edges from a slice of nodes
+pub fn get_edges(boxes: &[Node]) -> BinaryHeap<Reverse<Edge>> {
+    boxes
+        .iter()
+        .enumerate()
+        .fold(BinaryHeap::new(), |edges, (i, a)| {
+            boxes.iter().skip(i + 1).fold(edges, |mut edges, b| {
+                let weight: i64 = zip(a, b).map(|(a, b)| (a - b).pow(2)).sum();
+                let mut vertices = [*a, *b];
+                vertices.sort();
+                edges.push(Reverse(Edge(weight, vertices)));
+                edges
+            })
+        })
+}
+
+// Connect the nodes until there is one circuit of size n, or return None if the edges
+// run out first, as they do for fewer than two nodes or for repeated nodes
+pub fn connect_all(edges: &mut BinaryHeap<Reverse<Edge>>, n: usize) -> Option<Vec<[Node; 2]>> {
+    let mut connected_edges: Vec<[Node; 2]> = Vec::new();
+    let mut circuits: Vec<BTreeSet<Node>> = Vec::new();
+    while !(circuits.len() == 1 && circuits.first().unwrap().len() == n) {
+        let Reverse(Edge(_, nodes)) = edges.pop()?;
+        connected_edges.push(nodes);
+        circuits = add_edge(&circuits, &nodes);
+    }
+    Some(connected_edges)
+}
+
+pub fn parse_boxes(text: &str) -> Result<Vec<Node>, ParseError> {
+    text.lines()
+        .map(|line| {
+            let nums = line
+                .split(',')
+                .map(|d| {
+                    d.parse::<i64>()
+                        .map_err(|_| ParseError::at(text, d, "an integer"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            nums.try_into()
+                .map_err(|_| ParseError::at(text, line, "3 coordinates"))
+        })
+        .collect()
+}
+
+// Distinct junction boxes at random coordinates up to 99999
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut boxes = BTreeSet::new();
+    let mut text = String::new();
+    while boxes.len() < size {
+        let node: Node = [0; 3].map(|_| rng.range(0..=99_999) as i64);
+        if boxes.insert(node) {
+            text += &format!("{},{},{}\n", node[0], node[1], node[2]);
+        }
+    }
+    text
+}
+
+pub struct Day08;
+
+impl Day08 {
+    const CONNECTIONS: usize = 1000;
+}
+
+impl Solution for Day08 {
+    type Input = Vec<Node>;
+    type Answer = i64;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        Ok(parse_boxes(text)?)
+    }
+
+    // Multiply the sizes of the three largest circuits
+    fn part1(boxes: &Self::Input) -> Result<Self::Answer, Error> {
+        let mut circuits = connect_n(&mut get_edges(boxes), Self::CONNECTIONS);
+        circuits.sort_by_key(|b| Reverse(b.len()));
+        Ok(circuits.iter().take(3).map(|a| a.len() as i64).product())
+    }
+
+    // Multiply the x coordinates of the last connection that joins every box
+    fn part2(boxes: &Self::Input) -> Result<Self::Answer, Error> {
+        let whole_circuit = connect_all(&mut get_edges(boxes), boxes.len());
+        let last = whole_circuit.and_then(|edges| edges.last().copied());
+        let [[x0, _, _], [x1, _, _]] = last.ok_or(Error::NoAnswer)?;
+        Ok(x0 * x1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOXES: [Node; 20] = [
+        [162, 817, 812],
+        [57, 618, 57],
+        [906, 360, 560],
+        [592, 479, 940],
+        [352, 342, 300],
+        [466, 668, 158],
+        [542, 29, 236],
+        [431, 825, 988],
+        [739, 650, 466],
+        [52, 470, 668],
+        [216, 146, 977],
+        [819, 987, 18],
+        [117, 168, 530],
+        [805, 96, 715],
+        [346, 949, 466],
+        [970, 615, 88],
+        [941, 993, 340],
+        [862, 61, 35],
+        [984, 92, 344],
+        [425, 690, 689],
+    ];
+
+    #[test]
+    fn test_connect_n() {
+        let mut edges = get_edges(&BOXES);
+        assert_eq!(edges.len(), (20 * 19) / 2);
+        let circuits = connect_n(&mut edges, 10);
+        let mut sizes: Vec<_> = circuits.iter().map(|x| x.len()).collect();
+        sizes.sort_by_key(|a| std::cmp::Reverse(*a));
+        assert_eq!(sizes, [5, 4, 2, 2]);
+    }
+
+    #[test]
+    fn test_get_edges() {
+        let edges = get_edges(&BOXES);
+        let Reverse(Edge(_, nodes)) = edges.peek().unwrap();
+        assert_eq!(nodes, &[[162, 817, 812], [425, 690, 689]]);
+    }
+
+    #[test]
+    fn test_connect_all() {
+        let mut edges = get_edges(&BOXES);
+        let circuit = connect_all(&mut edges, BOXES.len()).unwrap();
+        assert_eq!(circuit.last().unwrap(), &[[117, 168, 530], [216, 146, 977]]);
+        // the edges run out before a lone box or a repeated one is joined up
+        assert_eq!(connect_all(&mut get_edges(&BOXES[..1]), 1), None);
+        let mut boxes = BOXES[..3].to_vec();
+        boxes.push(BOXES[0]);
+        assert_eq!(connect_all(&mut get_edges(&boxes), boxes.len()), None);
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(Day08::part2(&BOXES.to_vec()).unwrap(), 25272);
+        for boxes in [vec![], BOXES[..1].to_vec(), vec![BOXES[0]; 2]] {
+            assert!(matches!(Day08::part2(&boxes), Err(Error::NoAnswer)));
+        }
+    }
+
+    #[test]
+    fn test_parse_boxes() {
+        let boxes = parse_boxes("162,817,812\n57,618,57\n").unwrap();
+        assert_eq!(boxes, BOXES[..2]);
+        let e = parse_boxes("162,817,812\n57,6x8,57\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        let e = parse_boxes("162,817\n").unwrap_err();
+        assert_eq!(e.expected, "3 coordinates");
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // twenty distinct boxes
+        let boxes = Day08::parse(&text).unwrap();
+        assert_eq!(boxes.iter().collect::<BTreeSet<_>>().len(), 20);
+        assert_eq!(generate(&mut Rng::new(1), 0), "");
+    }
+}
@@ -0,0 +1,136 @@
+// Advent of Code 2025 Day 9
+// A. Drew
+
+use crate::rng::Rng;
+use crate::{Error, ParseError, Solution};
+
+pub fn largest_rectangle(tiles: &[[i64; 2]]) -> Option<[[i64; 2]; 2]> {
+    tiles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| tiles.iter().skip(i + 1).map(move |b| [a, b]))
+        .max_by_key(|[a, b]| area(&[**a, **b]))
+        .map(|[a, b]| [*a, *b])
+}
+
+pub fn area([[x0, y0], [x1, y1]]: &[[i64; 2]; 2]) -> i64 {
+    ((x1 - x0).abs() + 1) * ((y1 - y0).abs() + 1)
+}
+
+pub fn parse_tile(line: &str) -> Result<[i64; 2], ParseError> {
+    let elements = line
+        .split(',')
+        .map(|input| parse_coord(line, input))
+        .collect::<Result<Vec<_>, _>>()?;
+    elements
+        .try_into()
+        .map_err(|_| ParseError::at(line, line, "2 coordinates"))
+}
+
+fn parse_coord(line: &str, input: &str) -> Result<i64, ParseError> {
+    input
+        .parse()
+        .map_err(|_| ParseError::at(line, input, "an integer"))
+}
+
+// The red tiles at the corners of a random rectilinear polygon, in order around
+// its edge. The polygon is a skyline of columns standing on y = 0.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let columns = (size / 2).max(1);
+    let xs: Vec<i64> = (0..=columns)
+        .scan(0, |x, _| {
+            *x += rng.range(1..=1000) as i64;
+            Some(*x)
+        })
+        .collect();
+    let mut heights: Vec<i64> = Vec::with_capacity(columns);
+    while heights.len() < columns {
+        let h = rng.range(1..=100_000) as i64;
+        if heights.last() != Some(&h) {
+            heights.push(h);
+        }
+    }
+    let tops = heights
+        .iter()
+        .enumerate()
+        .flat_map(|(i, h)| [[xs[i], *h], [xs[i + 1], *h]]);
+    std::iter::once([xs[0], 0])
+        .chain(tops)
+        .chain(std::iter::once([xs[columns], 0]))
+        .map(|[x, y]| format!("{},{}\n", x, y))
+        .collect()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<[i64; 2]>;
+    type Answer = i64;
+
+    fn parse(text: &str) -> Result<Self::Input, Error> {
+        text.lines()
+            .enumerate()
+            .map(|(i, line)| parse_tile(line).map_err(|e| e.on_line(i + 1).into()))
+            .collect()
+    }
+
+    fn part1(tiles: &Self::Input) -> Result<Self::Answer, Error> {
+        largest_rectangle(tiles)
+            .as_ref()
+            .map(area)
+            .ok_or(Error::NoAnswer)
+    }
+
+    fn part2(_: &Self::Input) -> Result<Self::Answer, Error> {
+        Err(Error::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TILES: [[i64; 2]; 8] = [
+        [7, 1],
+        [11, 1],
+        [11, 7],
+        [9, 7],
+        [9, 5],
+        [2, 5],
+        [2, 3],
+        [7, 3],
+    ];
+
+    #[test]
+    fn test_sample() {
+        let pair = largest_rectangle(&TILES).unwrap();
+        assert_eq!(area(&pair), 50);
+    }
+
+    #[test]
+    fn test_parse() {
+        let pair = parse_tile("7,1").unwrap();
+        assert_eq!(pair, [7, 1]);
+        let e = parse_tile("7,x").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (3, "an integer"));
+        let e = parse_tile("7,1,2").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (1, "2 coordinates"));
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(Day09::part1(&TILES.to_vec()).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(&mut Rng::new(1), 20);
+        // ten columns have two corners each, and the skyline two more on y = 0
+        let tiles = Day09::parse(&text).unwrap();
+        assert_eq!(tiles.len(), 22);
+        assert_eq!((tiles[0][1], tiles[21][1]), (0, 0));
+        // each tile turns a corner from the one before it
+        let mut edges = tiles.iter().zip(tiles.iter().cycle().skip(1));
+        assert!(edges.all(|(p, q)| (p[0] == q[0]) != (p[1] == q[1])));
+    }
+}
@@ -0,0 +1,41 @@
+// Error type shared by every day's solution
+// A. Drew
+
+use crate::ParseError;
+
+#[derive(Debug)]
+pub enum Error {
+    Read(std::io::Error),
+    Parse(ParseError),
+    Usage(String),
+    NoAnswer,
+    Overflow,
+    Unsolved,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Read(e) => e.fmt(f),
+            Error::Parse(e) => e.fmt(f),
+            Error::Usage(e) => e.fmt(f),
+            Error::NoAnswer => write!(f, "input has no answer"),
+            Error::Overflow => write!(f, "answer is too large to count"),
+            Error::Unsolved => write!(f, "part is not solved yet"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Read(value)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Parse(value)
+    }
+}
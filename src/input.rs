@@ -0,0 +1,82 @@
+// Where the puzzle input is read from
+// A. Drew
+
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+// Environment variable that overrides the default data directory
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    // A command line argument naming the input, where "-" means stdin
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read_to_string(&self) -> std::io::Result<String> {
+        let mut text = String::new();
+        self.open()?.read_to_string(&mut text)?;
+        Ok(text)
+    }
+
+    pub fn open(&self) -> std::io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Source::Stdin => Box::new(std::io::stdin().lock()),
+            Source::Path(path) => Box::new(BufReader::new(std::fs::File::open(path)?)),
+        })
+    }
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Path(path) => path.display().fmt(f),
+        }
+    }
+}
+
+// The root of the per-day data directories, data/ unless overridden by the environment
+pub fn data_dir() -> PathBuf {
+    data_dir_from(std::env::var_os(DATA_DIR_VAR))
+}
+
+fn data_dir_from(var: Option<OsString>) -> PathBuf {
+    var.filter(|dir| !dir.is_empty())
+        .map_or_else(|| PathBuf::from("data"), PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("data/day02/input.txt"),
+            Source::Path(PathBuf::from("data/day02/input.txt"))
+        );
+        assert_eq!(Source::Stdin.to_string(), "<stdin>");
+    }
+
+    #[test]
+    fn test_data_dir() {
+        assert_eq!(data_dir_from(None), PathBuf::from("data"));
+        assert_eq!(data_dir_from(Some("".into())), PathBuf::from("data"));
+        assert_eq!(
+            data_dir_from(Some("/srv/aoc".into())),
+            PathBuf::from("/srv/aoc")
+        );
+    }
+}
@@ -0,0 +1,64 @@
+// Just enough JSON to write flat records, one object per line
+// A. Drew
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value<'a> {
+    Null,
+    Number(u128),
+    String(&'a str),
+}
+
+// Encode the fields, in order, as a JSON object
+pub fn object(fields: &[(&str, Value)]) -> String {
+    let fields: Vec<_> = fields
+        .iter()
+        .map(|(key, value)| format!("{}:{}", string(key), encode(value)))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn encode(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_owned(),
+        Value::Number(x) => x.to_string(),
+        Value::String(s) => string(s),
+    }
+}
+
+fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object() {
+        let record = object(&[
+            ("day", Value::Number(5)),
+            ("answer", Value::String("a \"b\"\n\\")),
+            ("error", Value::Null),
+            ("bell", Value::String("\u{7}")),
+        ]);
+        assert_eq!(
+            record,
+            r#"{"day":5,"answer":"a \"b\"\n\\","error":null,"bell":"\u0007"}"#
+        );
+        assert_eq!(object(&[]), "{}");
+    }
+}
@@ -0,0 +1,29 @@
+// Advent of Code 2025
+// A. Drew
+
+mod error;
+mod solution;
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod input;
+pub mod json;
+pub mod parse;
+pub mod reference;
+pub mod rng;
+pub mod runner;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+
+pub use error::Error;
+pub use parse::ParseError;
+pub use solution::{Settings, Solution};
@@ -0,0 +1,166 @@
+// Parse errors that point at the offending line and column of the input
+// A. Drew
+
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+use nom::{combinator::all_consuming, Parser};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub snippet: String,
+}
+
+impl ParseError {
+    // An error offset bytes into line, which is line number of the input counting from 1
+    pub fn at_line(number: usize, line: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let line = line.trim_end_matches(['\r', '\n']);
+        ParseError {
+            line: number,
+            column: line[..offset.min(line.len())].chars().count() + 1,
+            expected: expected.into(),
+            snippet: line.to_owned(),
+        }
+    }
+
+    // An error at the start of rest, which must be a slice of text
+    pub fn at(text: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .saturating_sub(text.as_ptr() as usize)
+            .min(text.len());
+        let start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = text[offset..].find('\n').map_or(text.len(), |i| offset + i);
+        let number = text[..start].matches('\n').count() + 1;
+        Self::at_line(number, &text[start..end], offset - start, expected)
+    }
+
+    // Renumber an error found by parsing a single line on its own
+    pub fn on_line(self, number: usize) -> Self {
+        ParseError {
+            line: number,
+            ..self
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// The nom error type used by the parsers, which remembers what it expected to find
+#[derive(Debug, PartialEq)]
+pub struct Expected<'a> {
+    pub input: &'a str,
+    pub expected: String,
+    context: bool,
+}
+
+pub type IResult<'a, O> = nom::IResult<&'a str, O, Expected<'a>>;
+
+impl<'a> nom::error::ParseError<&'a str> for Expected<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        let expected = match kind {
+            ErrorKind::Eof => "end of input",
+            ErrorKind::Digit => "a digit",
+            ErrorKind::MapRes => "a number in range",
+            ErrorKind::MultiSpace | ErrorKind::Space => "whitespace",
+            _ => kind.description(),
+        }
+        .to_owned();
+        Expected {
+            input,
+            expected,
+            context: false,
+        }
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Expected {
+            input,
+            expected: format!("{:?}", c),
+            context: false,
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+// The innermost context describes the failure best
+impl<'a> ContextError<&'a str> for Expected<'a> {
+    fn add_context(_: &'a str, context: &'static str, other: Self) -> Self {
+        if other.context {
+            other
+        } else {
+            Expected {
+                expected: context.to_owned(),
+                context: true,
+                ..other
+            }
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Expected<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+// Run parser over the whole of text, locating any failure within it
+pub fn parse_all<'a, O>(
+    text: &'a str,
+    parser: impl Parser<&'a str, Output = O, Error = Expected<'a>>,
+) -> Result<O, ParseError> {
+    all_consuming(parser)
+        .parse(text)
+        .map(|(_, x)| x)
+        .map_err(|e| match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => ParseError::at(text, e.input, e.expected),
+            nom::Err::Incomplete(_) => ParseError::at(text, &text[text.len()..], "more input"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let text = "L68\nL6x\nR48\n";
+        let e = ParseError::at(text, &text[6..], "a digit");
+        assert_eq!(
+            e,
+            ParseError {
+                line: 2,
+                column: 3,
+                expected: "a digit".to_owned(),
+                snippet: "L6x".to_owned(),
+            }
+        );
+        assert_eq!(ParseError::at(text, &text[12..], "more").line, 4);
+    }
+
+    #[test]
+    fn test_display() {
+        let e = ParseError::at_line(10, "3-", 2, "a number");
+        assert_eq!(
+            e.to_string(),
+            "line 10, column 3: expected a number\n   |\n10 | 3-\n   |   ^"
+        );
+    }
+}
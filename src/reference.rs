@@ -0,0 +1,657 @@
+// Slow but obviously correct versions of the solvers, for checking the fast ones
+// A. Drew
+//
+// Each of these does the most literal thing the puzzle describes, so they are only
+// fit for small inputs. The tests below compare them with the real solvers on
+// random inputs.
+
+pub mod day01 {
+    use crate::day01::Int;
+
+    // Turn a dial of the given size one click at a time, returning where it stops
+    // and how many clicks left it pointing at zero
+    pub fn spin(size: Int, position: Int, rotation: Int) -> (Int, Int) {
+        let step = rotation.signum();
+        (0..rotation.abs()).fold((position.rem_euclid(size), 0), |(position, clicks), _| {
+            let position = (position + step).rem_euclid(size);
+            (position, clicks + (position == 0) as Int)
+        })
+    }
+
+    // Follow the dial click by click, counting the rotations that stop at target and
+    // the clicks that point at it
+    pub fn visits(size: Int, start: Int, rotations: &[Int], target: Int) -> [Int; 2] {
+        let mut position = start.rem_euclid(size);
+        let mut visits = [0, 0];
+        for rotation in rotations {
+            for _ in 0..rotation.abs() {
+                position = (position + rotation.signum()).rem_euclid(size);
+                visits[1] += (position == target) as Int;
+            }
+            visits[0] += (position == target) as Int;
+        }
+        visits
+    }
+
+    // The fewest clicks of any plan that stops at each of the stops, trying both
+    // ways round to every stop
+    pub fn plan_clicks(size: Int, start: Int, stops: &[Int], cap: usize) -> Option<Int> {
+        (0..1 << stops.len())
+            .filter_map(|ways: u32| {
+                let (mut position, mut clicks, mut zeros) = (start, 0, 0);
+                for (k, stop) in stops.iter().enumerate() {
+                    let right = (stop - position).rem_euclid(size);
+                    let rotation = if ways >> k & 1 == 1 && right > 0 {
+                        right - size
+                    } else {
+                        right
+                    };
+                    let (next, z) = spin(size, position, rotation);
+                    (position, clicks, zeros) = (next, clicks + rotation.abs(), zeros + z);
+                }
+                (zeros <= cap as Int).then_some(clicks)
+            })
+            .min()
+    }
+}
+
+pub mod day02 {
+    // Try every way of cutting the digits of each id in the radix into equal blocks
+    pub fn repeated(range: [u64; 2], radix: u32, times: impl Fn(usize) -> bool) -> Vec<u64> {
+        let [a, b] = range;
+        (a..=b)
+            .filter(|id| {
+                let mut digits = Vec::new();
+                let mut x = *id;
+                while x > 0 || digits.is_empty() {
+                    digits.insert(0, x % radix as u64);
+                    x /= radix as u64;
+                }
+                let n = digits.len();
+                (2..=n).any(|k| n % k == 0 && times(k) && digits == digits[..n / k].repeat(k))
+            })
+            .collect()
+    }
+
+    pub fn repeated_twice(range: [u64; 2]) -> Vec<u64> {
+        repeated(range, 10, |k| k == 2)
+    }
+
+    pub fn repeated_any(range: [u64; 2]) -> Vec<u64> {
+        repeated(range, 10, |_| true)
+    }
+}
+
+pub mod day03 {
+    // Try every choice of batteries, keeping their order in the bank
+    pub fn largest_joltage(batteries: u32, bank: &[u64]) -> Option<u64> {
+        match (batteries, bank) {
+            (0, _) => Some(0),
+            (_, []) => None,
+            (_, [first, rest @ ..]) => {
+                let with = largest_joltage(batteries - 1, rest)
+                    .map(|joltage| first * 10u64.pow(batteries - 1) + joltage);
+                let without = largest_joltage(batteries, rest);
+                with.max(without)
+            }
+        }
+    }
+}
+
+pub mod day04 {
+    use std::collections::BTreeSet;
+
+    fn neighbours(rolls: &BTreeSet<[isize; 2]>, [i, j]: [isize; 2]) -> usize {
+        (i - 1..=i + 1)
+            .flat_map(|ii| (j - 1..=j + 1).map(move |jj| [ii, jj]))
+            .filter(|x| *x != [i, j] && rolls.contains(x))
+            .count()
+    }
+
+    pub fn accessible(rolls: &[[isize; 2]]) -> Vec<[isize; 2]> {
+        let grid: BTreeSet<_> = rolls.iter().copied().collect();
+        rolls
+            .iter()
+            .copied()
+            .filter(|roll| neighbours(&grid, *roll) < 4)
+            .collect()
+    }
+
+    // Remove accessible rolls one at a time until none are left
+    pub fn count_removable(rolls: &[[isize; 2]]) -> usize {
+        let mut grid: BTreeSet<_> = rolls.iter().copied().collect();
+        let mut removed = 0;
+        while let Some(roll) = grid
+            .iter()
+            .copied()
+            .find(|roll| neighbours(&grid, *roll) < 4)
+        {
+            grid.remove(&roll);
+            removed += 1;
+        }
+        removed
+    }
+}
+
+pub mod day05 {
+    use std::collections::BTreeSet;
+    use std::ops::RangeInclusive;
+
+    // Count the fresh ids by listing every one of them
+    pub fn count_fresh_ids(fresh: &[RangeInclusive<u64>]) -> usize {
+        fresh
+            .iter()
+            .flat_map(|range| range.clone())
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+}
+
+pub mod day06 {
+    // Cut the worksheet into problems at the columns that are blank on every line,
+    // then read the operands across the rows and down the columns. Returns the
+    // grand totals for both readings.
+    pub fn solve(text: &str) -> (u64, u64) {
+        let grid: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let at = |row: usize, column: usize| grid[row].get(column).copied().unwrap_or(' ');
+        let blank = |column: usize| (0..grid.len()).all(|row| at(row, column) == ' ');
+        let mut totals = (0, 0);
+        let mut start = 0;
+        while start < width {
+            let end = (start..width).find(|c| blank(*c)).unwrap_or(width);
+            let operands = grid.len() - 1;
+            let number = |cells: String| cells.trim().parse::<u64>().expect("a number");
+            let rows = (0..operands).map(|row| number((start..end).map(|c| at(row, c)).collect()));
+            let cols = (start..end).map(|c| number((0..operands).map(|row| at(row, c)).collect()));
+            let (rows, cols): (Vec<_>, Vec<_>) = (rows.collect(), cols.collect());
+            if at(operands, start) == '*' {
+                totals.0 += rows.iter().product::<u64>();
+                totals.1 += cols.iter().product::<u64>();
+            } else {
+                totals.0 += rows.iter().sum::<u64>();
+                totals.1 += cols.iter().sum::<u64>();
+            }
+            start = end + 1;
+        }
+        totals
+    }
+}
+
+pub mod day07 {
+    use std::collections::BTreeSet;
+
+    // Follow each timeline on its own, which doubles the work at every split
+    pub fn count_timelines(beam: isize, splitters: &[Vec<isize>]) -> usize {
+        match splitters.split_first() {
+            None => 1,
+            Some((row, rest)) if row.contains(&beam) => {
+                count_timelines(beam - 1, rest) + count_timelines(beam + 1, rest)
+            }
+            Some((_, rest)) => count_timelines(beam, rest),
+        }
+    }
+
+    // Move the set of beams down a row at a time, counting the splitters they hit
+    pub fn count_splits(beam: isize, splitters: &[Vec<isize>]) -> usize {
+        let mut beams = BTreeSet::from([beam]);
+        let mut splits = 0;
+        for row in splitters {
+            beams = beams
+                .iter()
+                .flat_map(|beam| {
+                    if row.contains(beam) {
+                        splits += 1;
+                        vec![beam - 1, beam + 1]
+                    } else {
+                        vec![*beam]
+                    }
+                })
+                .collect();
+        }
+        splits
+    }
+}
+
+pub mod day08 {
+    use crate::day08::Node;
+
+    fn distance(a: &Node, b: &Node) -> i64 {
+        std::iter::zip(a, b).map(|(a, b)| (a - b).pow(2)).sum()
+    }
+
+    // Every pair of boxes, closest first
+    fn pairs(boxes: &[Node]) -> Vec<[usize; 2]> {
+        let mut pairs: Vec<_> = (0..boxes.len())
+            .flat_map(|i| (i + 1..boxes.len()).map(move |j| [i, j]))
+            .collect();
+        pairs.sort_by_key(|[i, j]| distance(&boxes[*i], &boxes[*j]));
+        pairs
+    }
+
+    // Label every box with its circuit, relabelling one whole circuit on each join.
+    // Returns whether the pair were in different circuits.
+    fn join(circuit: &mut [usize], [i, j]: [usize; 2]) -> bool {
+        let (from, to) = (circuit[j], circuit[i]);
+        circuit
+            .iter_mut()
+            .filter(|c| **c == from)
+            .for_each(|c| *c = to);
+        from != to
+    }
+
+    // The sizes of the circuits of more than one box after the given number of the
+    // closest pairs are connected, largest first
+    pub fn circuit_sizes(boxes: &[Node], connections: usize) -> Vec<usize> {
+        let mut circuit: Vec<_> = (0..boxes.len()).collect();
+        for pair in pairs(boxes).into_iter().take(connections) {
+            join(&mut circuit, pair);
+        }
+        let mut sizes: Vec<_> = (0..boxes.len())
+            .map(|c| circuit.iter().filter(|x| **x == c).count())
+            .filter(|n| *n > 1)
+            .collect();
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    // The connection that finally puts every box in one circuit
+    pub fn last_connection(boxes: &[Node]) -> Option<[Node; 2]> {
+        let mut circuit: Vec<_> = (0..boxes.len()).collect();
+        let mut circuits = boxes.len();
+        for [i, j] in pairs(boxes) {
+            if join(&mut circuit, [i, j]) {
+                circuits -= 1;
+                if circuits == 1 {
+                    let mut pair = [boxes[i], boxes[j]];
+                    pair.sort();
+                    return Some(pair);
+                }
+            }
+        }
+        None
+    }
+}
+
+pub mod day09 {
+    pub fn largest_area(tiles: &[[i64; 2]]) -> Option<i64> {
+        let mut largest = None;
+        for (i, [x0, y0]) in tiles.iter().enumerate() {
+            for [x1, y1] in &tiles[i + 1..] {
+                let area = ((x1 - x0).abs() + 1) * ((y1 - y0).abs() + 1);
+                largest = largest.max(Some(area));
+            }
+        }
+        largest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::Rng;
+    use crate::Solution;
+
+    const CASES: u64 = 200;
+
+    fn sorted<T: Ord>(mut xs: Vec<T>) -> Vec<T> {
+        xs.sort();
+        xs
+    }
+
+    #[test]
+    fn test_day01() {
+        use crate::day01::{expand, histogram, passwords_by_start, process, Day01, Dial, Int};
+        let mut rng = Rng::new(1);
+        for _ in 0..CASES {
+            let size = *rng.choose(&[1, 7, 60, 100, 256, 1000]);
+            let position = rng.range(0..=size as u64 - 1) as Int;
+            let rotation = rng.range(0..=2000) as Int - 1000;
+            let mut dial = Dial::with_size(size, position);
+            let clicks = dial.spin(rotation);
+            assert_eq!(
+                (dial.read(), clicks),
+                super::day01::spin(size, position, rotation),
+                "{} from {} of {}",
+                rotation,
+                position,
+                size
+            );
+        }
+        for _ in 0..CASES {
+            let size = *rng.choose(&[1, 6, 7, 60, 100]);
+            let position = rng.range(0..=size as u64 - 1) as Int;
+            let rotation = rng.range(0..=300) as Int - 150;
+            let count = rng.index(40);
+            let expected = (0..count).fold((position, 0, 0), |(position, landings, clicks), _| {
+                let (next, c) = super::day01::spin(size, position, rotation);
+                (next, landings + (next == 0) as Int, clicks + c)
+            });
+            let mut dial = Dial::with_size(size, position);
+            let (landings, clicks) = dial.checked_spin_run(count, rotation).unwrap();
+            assert_eq!(
+                (dial.read(), landings, clicks),
+                expected,
+                "{} x {} from {} of {}",
+                count,
+                rotation,
+                position,
+                size
+            );
+        }
+        for seed in 0..CASES {
+            let text = crate::day01::generate(&mut Rng::new(seed), 20);
+            let rotations: Vec<_> = expand(&Day01::parse(&text).unwrap().runs).collect();
+            let expected = rotations.iter().scan(Dial::START, |position, rotation| {
+                let (next, clicks) = super::day01::spin(Dial::SIZE, *position, *rotation);
+                *position = next;
+                Some((next, clicks))
+            });
+            assert!(
+                process(Dial::default(), rotations.iter().copied()).eq(expected),
+                "seed {}",
+                seed
+            );
+        }
+        for seed in 0..CASES {
+            let mut rng = Rng::new(seed);
+            let size = *rng.choose(&[1, 7, 60, 100, 256]);
+            let start = rng.range(0..=size as u64 - 1) as Int;
+            let runs = Day01::parse(&crate::day01::generate(&mut rng, 10))
+                .unwrap()
+                .runs;
+            let rotations: Vec<_> = expand(&runs).collect();
+            let passwords = passwords_by_start(size, rotations.iter().copied()).unwrap();
+            let visits =
+                histogram(Dial::with_size(size, start), rotations.iter().copied()).unwrap();
+            assert_eq!(
+                passwords[start as usize],
+                (visits[0].landings, visits[0].clicks),
+                "seed {}",
+                seed
+            );
+            for (target, visits) in (0..).zip(visits) {
+                assert_eq!(
+                    [visits.landings, visits.clicks],
+                    super::day01::visits(size, start, &rotations, target),
+                    "seed {} at {}",
+                    seed,
+                    target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_day01_plan() {
+        use crate::day01::{plan, process, Dial, Int};
+        let mut rng = Rng::new(11);
+        for _ in 0..CASES {
+            let size = *rng.choose(&[2, 7, 100]);
+            let start = rng.range(0..=size as u64 - 1) as Int;
+            let stops: Vec<_> = (0..rng.range(0..=8))
+                .map(|_| rng.range(0..=size as u64 - 1) as Int)
+                .collect();
+            let dial = Dial::with_size(size, start);
+            let shortest = plan(dial, &stops, None).unwrap();
+            assert_eq!(
+                Some(shortest.iter().map(|r| r.abs()).sum()),
+                super::day01::plan_clicks(size, start, &stops, stops.len()),
+                "{:?} from {} of {}",
+                stops,
+                start,
+                size
+            );
+            let cap = rng.index(4);
+            let rotations = plan(dial, &stops, Some(cap));
+            assert_eq!(
+                rotations.as_ref().map(|r| r.iter().map(|r| r.abs()).sum()),
+                super::day01::plan_clicks(size, start, &stops, cap),
+                "{:?} from {} of {} with at most {}",
+                stops,
+                start,
+                size,
+                cap
+            );
+            if let Some(rotations) = rotations {
+                let (positions, clicks): (Vec<_>, Vec<_>) = process(dial, rotations).unzip();
+                assert_eq!(positions, stops);
+                assert!(clicks.iter().sum::<Int>() <= cap as Int);
+            }
+        }
+    }
+
+    #[test]
+    fn test_day02() {
+        use crate::day02::{
+            count_invalid, find_repeated_any, find_repeated_twice, sum_invalid, Policy,
+        };
+        let mut rng = Rng::new(2);
+        for _ in 0..CASES {
+            let digits = rng.range(1..=7) as u32;
+            let a = rng.range(1..=10u64.pow(digits));
+            let range = [a, a + rng.below(2000)];
+            assert_eq!(
+                find_repeated_twice(range),
+                super::day02::repeated_twice(range),
+                "{:?}",
+                range
+            );
+            assert_eq!(
+                find_repeated_any(range),
+                super::day02::repeated_any(range),
+                "{:?}",
+                range
+            );
+            for (policy, ids) in [
+                (Policy::Twice, super::day02::repeated_twice(range)),
+                (Policy::Any, super::day02::repeated_any(range)),
+            ] {
+                assert_eq!(
+                    (count_invalid(range, policy), sum_invalid(range, policy)),
+                    (ids.len() as u64, ids.iter().map(|x| *x as u128).sum()),
+                    "{:?} {:?}",
+                    policy,
+                    range
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_day02_overlaps() {
+        use crate::day02::{merge, orient, sum_invalid, Policy, Reversed};
+        use std::collections::BTreeSet;
+        let mut rng = Rng::new(2);
+        for _ in 0..CASES {
+            let ranges: Vec<_> = (0..rng.range(1..=6))
+                .map(|_| [rng.range(1..=3000), rng.range(1..=3000)])
+                .collect();
+            let merged = merge(&orient(&ranges, Reversed::Swap).0);
+            let ids: BTreeSet<_> = ranges
+                .iter()
+                .flat_map(|&[a, b]| super::day02::repeated_any([a.min(b), a.max(b)]))
+                .collect();
+            assert_eq!(
+                merged
+                    .iter()
+                    .map(|r| sum_invalid(*r, Policy::Any))
+                    .sum::<u128>(),
+                ids.iter().map(|x| *x as u128).sum(),
+                "{:?}",
+                ranges
+            );
+            assert!(merged.windows(2).all(|w| w[0][1] + 1 < w[1][0]));
+        }
+    }
+
+    #[test]
+    fn test_day02_radix() {
+        use crate::day02::{count_invalid_radix, find_invalid_radix, sum_invalid_radix, Policy};
+        let mut rng = Rng::new(2);
+        for _ in 0..CASES {
+            let radix = rng.range(2..=36) as u32;
+            let digits = rng.range(1..=4) as u32;
+            let a = rng.range(1..=(radix as u64).pow(digits));
+            let range = [a, a + rng.below(2000)];
+            for (policy, twice) in [(Policy::Twice, true), (Policy::Any, false)] {
+                let ids = super::day02::repeated(range, radix, |k| k == 2 || !twice);
+                assert_eq!(
+                    find_invalid_radix(range, policy, radix),
+                    ids,
+                    "{:?} {:?} in base {}",
+                    policy,
+                    range,
+                    radix
+                );
+                assert_eq!(
+                    (
+                        count_invalid_radix(range, policy, radix),
+                        sum_invalid_radix(range, policy, radix)
+                    ),
+                    (ids.len() as u64, ids.iter().map(|x| *x as u128).sum()),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_day03() {
+        use crate::day03::largest_joltage;
+        let mut rng = Rng::new(3);
+        for _ in 0..CASES {
+            let bank: Vec<_> = (0..rng.range(0..=12)).map(|_| rng.range(1..=9)).collect();
+            let batteries = rng.range(0..=bank.len() as u64 + 2) as u32;
+            assert_eq!(
+                largest_joltage(batteries, &bank),
+                super::day03::largest_joltage(batteries, &bank),
+                "{} from {:?}",
+                batteries,
+                bank
+            );
+        }
+    }
+
+    #[test]
+    fn test_day04() {
+        use crate::day04::{count_removable, parse_rolls, partition_accessible};
+        for seed in 0..CASES {
+            let text = crate::day04::generate(&mut Rng::new(seed), 8);
+            let rolls = parse_rolls(&text).unwrap();
+            let (accessible, _) = partition_accessible(&rolls);
+            assert_eq!(
+                accessible,
+                super::day04::accessible(&rolls),
+                "seed {}",
+                seed
+            );
+            assert_eq!(
+                count_removable(rolls.clone()),
+                super::day04::count_removable(&rolls),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_day05() {
+        use crate::day05::{count_elements, merge_ranges};
+        let mut rng = Rng::new(5);
+        for _ in 0..CASES {
+            let fresh: Vec<_> = (0..rng.range(1..=8))
+                .map(|_| {
+                    let a = rng.range(0..=100);
+                    a..=a + rng.below(30)
+                })
+                .collect();
+            let merged: u64 = merge_ranges(&fresh).iter().map(count_elements).sum();
+            assert_eq!(
+                merged as usize,
+                super::day05::count_fresh_ids(&fresh),
+                "{:?}",
+                fresh
+            );
+        }
+    }
+
+    #[test]
+    fn test_day06() {
+        use crate::day06::Day06;
+        for seed in 0..CASES {
+            let text = crate::day06::generate(&mut Rng::new(seed), 10);
+            let worksheet = Day06::parse(&text).unwrap();
+            assert_eq!(
+                (
+                    Day06::part1(&worksheet).unwrap(),
+                    Day06::part2(&worksheet).unwrap()
+                ),
+                super::day06::solve(&text),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_day07() {
+        use crate::day07::{count_splits, count_timelines, parse_input};
+        for seed in 0..CASES {
+            let text = crate::day07::generate(&mut Rng::new(seed), 16);
+            let (beam, splitters) = parse_input(&text).unwrap();
+            assert_eq!(
+                count_timelines(&beam, &splitters),
+                super::day07::count_timelines(beam, &splitters),
+                "seed {}",
+                seed
+            );
+            assert_eq!(
+                count_splits(&beam, &splitters),
+                super::day07::count_splits(beam, &splitters),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_day08() {
+        use crate::day08::{connect_all, connect_n, get_edges, parse_boxes};
+        let mut rng = Rng::new(8);
+        for seed in 0..CASES {
+            let text = crate::day08::generate(&mut Rng::new(seed), 12);
+            let boxes = parse_boxes(&text).unwrap();
+            let connections = rng.index(boxes.len() * 2);
+            let circuits = connect_n(&mut get_edges(&boxes), connections);
+            let sizes = sorted(circuits.iter().map(|c| c.len()).collect());
+            assert_eq!(
+                sizes.into_iter().rev().collect::<Vec<_>>(),
+                super::day08::circuit_sizes(&boxes, connections),
+                "seed {} with {} connections",
+                seed,
+                connections
+            );
+            let edges = connect_all(&mut get_edges(&boxes), boxes.len()).unwrap();
+            assert_eq!(
+                edges.last().copied(),
+                super::day08::last_connection(&boxes),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_day09() {
+        use crate::day09::{area, largest_rectangle, parse_tile};
+        for seed in 0..CASES {
+            let text = crate::day09::generate(&mut Rng::new(seed), 20);
+            let tiles: Vec<_> = text.lines().map(|l| parse_tile(l).unwrap()).collect();
+            assert_eq!(
+                largest_rectangle(&tiles).map(|r| area(&r)),
+                super::day09::largest_area(&tiles),
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
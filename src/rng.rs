@@ -0,0 +1,79 @@
+// Small seedable random number generator for generating puzzle inputs
+// A. Drew
+//
+// This is SplitMix64, which is plenty for test inputs and keeps the crate free of
+// dependencies. The same seed always gives the same sequence.
+
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // A number in 0..n, which must not be empty
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        match (end - start).checked_add(1) {
+            Some(n) => start + self.below(n),
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn index(&mut self, n: usize) -> usize {
+        self.below(n as u64) as usize
+    }
+
+    // True with probability p
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let xs: Vec<_> = (0..5).map(|_| Rng::new(7).next_u64()).collect();
+        assert!(xs.iter().all(|x| *x == xs[0]));
+        let mut rng = Rng::new(7);
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((10..=20).contains(&rng.range(10..=20)));
+            assert!(rng.below(3) < 3);
+        }
+        assert_eq!(rng.range(5..=5), 5);
+        rng.range(0..=u64::MAX);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+}
@@ -0,0 +1,186 @@
+// Run any day's solution without knowing its input and answer types
+// A. Drew
+
+use crate::input::{data_dir, Source};
+use crate::rng::Rng;
+use crate::{day01, day02, day03, day04, day05, day06, day07, day08, day09};
+use crate::{Error, Settings, Solution};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn parse(input: &str) -> Option<Part> {
+        match input {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.number().fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub value: Result<String, Error>,
+    // other figures to report beside the value, by name
+    pub details: Vec<(String, String)>,
+    pub elapsed: Duration,
+}
+
+// The answers to one input along with how long it took to parse and any warnings
+// about the input
+#[derive(Debug)]
+pub struct Solved {
+    pub parse_elapsed: Duration,
+    pub warnings: Vec<String>,
+    pub answers: Vec<Answer>,
+}
+
+pub struct Day {
+    pub number: u32,
+    solve: fn(&str, &[Part], &Settings) -> Result<Solved, Error>,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+pub const DAYS: [Day; 9] = [
+    #[cfg(not(feature = "wide"))]
+    Day::new::<day01::Day01>(1, day01::generate),
+    #[cfg(feature = "wide")]
+    Day::new::<day01::WideDay01>(1, day01::generate),
+    Day::new::<day02::Day02>(2, day02::generate),
+    Day::new::<day03::Day03>(3, day03::generate),
+    Day::new::<day04::Day04>(4, day04::generate),
+    Day::new::<day05::Day05>(5, day05::generate),
+    Day::new::<day06::Day06>(6, day06::generate),
+    Day::new::<day07::Day07>(7, day07::generate),
+    Day::new::<day08::Day08>(8, day08::generate),
+    Day::new::<day09::Day09>(9, day09::generate),
+];
+
+impl Day {
+    const fn new<S: Solution>(number: u32, generate: fn(&mut Rng, usize) -> String) -> Self {
+        Day {
+            number,
+            solve: solve::<S>,
+            generate,
+        }
+    }
+
+    pub fn find(number: u32) -> Option<&'static Day> {
+        DAYS.iter().find(|day| day.number == number)
+    }
+
+    // A file in this day's data directory
+    pub fn data_path(&self, name: &str) -> PathBuf {
+        data_dir().join(format!("day{:02}", self.number)).join(name)
+    }
+
+    // The puzzle input when no other source is given
+    pub fn input(&self) -> Source {
+        Source::Path(self.data_path("input.txt"))
+    }
+
+    // Parse the text for the settings then solve each of the requested parts
+    pub fn solve(&self, text: &str, parts: &[Part], settings: &Settings) -> Result<Solved, Error> {
+        (self.solve)(text, parts, settings)
+    }
+
+    // A random input, where size scales the amount of input in a way that suits the day
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (self.generate)(rng, size)
+    }
+}
+
+fn solve<S: Solution>(text: &str, parts: &[Part], settings: &Settings) -> Result<Solved, Error> {
+    let start = Instant::now();
+    let input = S::parse_with(text, settings)?;
+    let parse_elapsed = start.elapsed();
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part1(&input),
+                Part::Two => S::part2(&input),
+            };
+            let elapsed = start.elapsed();
+            let details = match &value {
+                Ok(answer) => S::details(&input, part, answer),
+                Err(_) => Vec::new(),
+            };
+            Answer {
+                part,
+                value: value.map(|x| x.to_string()),
+                details,
+                elapsed,
+            }
+        })
+        .collect();
+    Ok(Solved {
+        parse_elapsed,
+        warnings: S::warnings(&input),
+        answers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(Day::find(5).map(|day| day.number), Some(5));
+        assert!(Day::find(10).is_none());
+        assert!(Day::find(7)
+            .unwrap()
+            .data_path("input.txt")
+            .ends_with("day07/input.txt"));
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = Day::find(1).unwrap();
+        let Solved { answers, .. } = day
+            .solve(
+                "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82",
+                &[Part::Two],
+                &Settings::default(),
+            )
+            .unwrap();
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].part, Part::Two);
+        assert_eq!(answers[0].value.as_deref().unwrap(), "6");
+        // the settings change where the dial starts
+        let settings = Settings {
+            dial: crate::day01::Dial::new(0),
+            ..Settings::default()
+        };
+        let Solved { answers, .. } = day.solve("R100\nL1", &Part::ALL, &settings).unwrap();
+        let values: Vec<_> = answers
+            .iter()
+            .map(|a| a.value.as_deref().unwrap())
+            .collect();
+        assert_eq!(values, ["1", "1"]);
+    }
+}
@@ -0,0 +1,58 @@
+// Common interface implemented by each day's puzzle
+// A. Drew
+
+use crate::day01::Dial;
+use crate::day02::Reversed;
+use crate::runner::Part;
+use crate::Error;
+
+// Settings that change how a day reads its input, taken from its binary's own
+// options. Each day uses only its own and ignores the rest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Settings {
+    // the dial day 1 starts from
+    pub dial: Dial,
+    // the radix day 2 ids are written in, from 2 to 36, and what to do with
+    // reversed day 2 ranges
+    pub radix: u32,
+    pub reversed: Reversed,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            dial: Dial::default(),
+            radix: 10,
+            reversed: Reversed::Swap,
+        }
+    }
+}
+
+// Parse the puzzle text once, then solve both parts from the parsed input
+pub trait Solution {
+    type Input;
+    type Answer: std::fmt::Display;
+
+    fn parse(text: &str) -> Result<Self::Input, Error>;
+
+    // Parse for the settings, which most days have none of
+    fn parse_with(text: &str, _settings: &Settings) -> Result<Self::Input, Error> {
+        Self::parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Error>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Error>;
+
+    // Anything odd about the input that the answers work around, such as a range
+    // written backwards
+    fn warnings(_input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+
+    // Other figures to report beside a part's answer, each with a name that suits
+    // a JSON key, such as the answer written in another radix
+    fn details(_input: &Self::Input, _part: Part, _answer: &Self::Answer) -> Vec<(String, String)> {
+        Vec::new()
+    }
+}